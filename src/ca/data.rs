@@ -0,0 +1,124 @@
+use core::ffi::{c_int, c_uint};
+
+//
+// ----- Slots
+
+/// CI high level interface
+pub const CA_CI: c_int = 1;
+/// CI link layer level interface
+pub const CA_CI_LINK: c_int = 2;
+/// CI physical layer level interface
+pub const CA_CI_PHYS: c_int = 4;
+/// built-in descrambler
+pub const CA_DESCR: c_int = 8;
+/// simple smart card interface
+pub const CA_SC: c_int = 128;
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca_data_types.html#c.ca_slot_info))
+///
+/// CA slot interface types and info.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CaSlotInfo {
+    /// slot number.
+    pub num: c_int,
+    /// slot type, one of [CA_CI], [CA_CI_LINK], [CA_CI_PHYS], [CA_DESCR] or [CA_SC].
+    pub type_: c_int,
+    /// flags applicable to the slot.
+    pub flags: c_uint,
+}
+
+impl CaSlotInfo {
+    const CA_CI_MODULE_PRESENT_BIT: c_uint = 1;
+    const CA_CI_MODULE_READY_BIT: c_uint = 2;
+
+    /// "module (or card) inserted"
+    pub fn module_present(&self) -> bool {
+        (self.flags & Self::CA_CI_MODULE_PRESENT_BIT) != 0
+    }
+
+    /// Module was initialized and can be talked to.
+    pub fn module_ready(&self) -> bool {
+        (self.flags & Self::CA_CI_MODULE_READY_BIT) != 0
+    }
+}
+
+//
+// ----- Capabilities
+
+/// European Common Descrambler (ECD) hardware
+pub const CA_ECD: c_uint = 1;
+/// Videoguard (NDS) hardware
+pub const CA_NDS: c_uint = 2;
+/// Distributed Sample Scrambling (DSS) hardware
+pub const CA_DSS: c_uint = 4;
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca_data_types.html#c.ca_caps))
+///
+/// CA slot interface capabilities.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CaCaps {
+    /// total number of CA card and module slots.
+    pub slot_num: c_uint,
+    /// bitmap with all supported types as defined at [CaSlotInfo] (e. g. [CA_CI], [CA_CI_LINK], etc).
+    pub slot_type: c_uint,
+    /// total number of descrambler slots (keys)
+    pub descr_num: c_uint,
+    /// bitmap with all supported types (e. g. [CA_ECD], [CA_NDS], [CA_DSS])
+    pub descr_type: c_uint,
+}
+
+//
+// ----- Messages
+
+/// Largest message that fits in a [CaMsg].
+pub const CA_MSG_MAX_LEN: usize = 256;
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca_data_types.html#c.ca_msg))
+///
+/// a message to/from a CI-CAM
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CaMsg {
+    /// unused
+    pub index: c_uint,
+    /// unused
+    pub type_: c_uint,
+    /// length of the message
+    pub length: c_uint,
+    /// message
+    pub msg: [u8; CA_MSG_MAX_LEN],
+}
+
+impl CaMsg {
+    /// Builds a message to send.
+    ///
+    /// Panics if the message is longer than [CA_MSG_MAX_LEN].
+    pub fn new(msg: &[u8]) -> CaMsg {
+        assert!(
+            msg.len() <= CA_MSG_MAX_LEN,
+            "CA messages are limited to {CA_MSG_MAX_LEN} bytes"
+        );
+        let mut ca_msg = CaMsg::default();
+        ca_msg.msg[..msg.len()].copy_from_slice(msg);
+        ca_msg.length = msg.len() as c_uint;
+        ca_msg
+    }
+
+    /// The message itself, up to its length.
+    pub fn bytes(&self) -> &[u8] {
+        &self.msg[..(self.length as usize).min(CA_MSG_MAX_LEN)]
+    }
+}
+
+impl Default for CaMsg {
+    fn default() -> Self {
+        CaMsg {
+            index: 0,
+            type_: 0,
+            length: 0,
+            msg: [0; CA_MSG_MAX_LEN],
+        }
+    }
+}
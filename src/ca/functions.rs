@@ -0,0 +1,62 @@
+use std::{
+    ffi::c_int,
+    os::fd::{AsRawFd as _, BorrowedFd},
+};
+
+use nix::errno::Errno;
+
+use crate::ca::{
+    data::{CaCaps, CaMsg, CaSlotInfo},
+    ioctl::{ca_get_cap, ca_get_msg, ca_get_slot_info, ca_reset, ca_send_msg},
+};
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca-reset.html#description))
+///
+/// Puts the Conditional Access hardware on its initial state. It should be called before start using the CA hardware.
+pub fn reset(fd: BorrowedFd) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, and this ioctl takes no argument. There should be no conditions or unhandled side-effects.
+    unsafe { ca_reset(fd.as_raw_fd()) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca-get-cap.html#description))
+///
+/// Queries the Kernel for information about the available CA and descrambler slots, and their types.
+pub fn get_cap(fd: BorrowedFd) -> Result<CaCaps, Errno> {
+    let mut caps = CaCaps::default();
+    // SAFETY: FD is always valid, CaCaps is C-compatible and always valid. There should be no conditions or unhandled side-effects.
+    unsafe { ca_get_cap(fd.as_raw_fd(), &mut caps) }?;
+    Ok(caps)
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca-get-slot-info.html#description))
+///
+/// Returns information about a CA slot identified by num.
+pub fn get_slot_info(fd: BorrowedFd, num: c_int) -> Result<CaSlotInfo, Errno> {
+    let mut info = CaSlotInfo {
+        num,
+        ..Default::default()
+    };
+    // SAFETY: FD is always valid, CaSlotInfo is C-compatible and always valid. There should be no conditions or unhandled side-effects.
+    unsafe { ca_get_slot_info(fd.as_raw_fd(), &mut info) }?;
+    Ok(info)
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca-get-msg.html#description))
+///
+/// Receives a message via a CI CA module.
+pub fn get_msg(fd: BorrowedFd) -> Result<CaMsg, Errno> {
+    let mut msg = CaMsg::default();
+    // SAFETY: FD is always valid, CaMsg is C-compatible and always valid. There should be no conditions or unhandled side-effects.
+    unsafe { ca_get_msg(fd.as_raw_fd(), &mut msg) }?;
+    Ok(msg)
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/ca-send-msg.html#description))
+///
+/// Sends a message via a CI CA module.
+pub fn send_msg(fd: BorrowedFd, msg: &CaMsg) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, CaMsg is C-compatible and only read by the kernel. There should be no conditions or unhandled side-effects.
+    unsafe { ca_send_msg(fd.as_raw_fd(), msg) }?;
+    Ok(())
+}
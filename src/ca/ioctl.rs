@@ -0,0 +1,21 @@
+use nix::{ioctl_none, ioctl_read, ioctl_write_ptr};
+
+use crate::{
+    IOCTL_TYPE,
+    ca::data::{CaCaps, CaMsg, CaSlotInfo},
+};
+
+const CA_RESET: u8 = 128;
+ioctl_none!(ca_reset, IOCTL_TYPE, CA_RESET);
+
+const CA_GET_CAP: u8 = 129;
+ioctl_read!(ca_get_cap, IOCTL_TYPE, CA_GET_CAP, CaCaps);
+
+const CA_GET_SLOT_INFO: u8 = 130;
+ioctl_read!(ca_get_slot_info, IOCTL_TYPE, CA_GET_SLOT_INFO, CaSlotInfo); // Declared as read, but the kernel also reads the slot number
+
+const CA_GET_MSG: u8 = 132;
+ioctl_read!(ca_get_msg, IOCTL_TYPE, CA_GET_MSG, CaMsg);
+
+const CA_SEND_MSG: u8 = 133;
+ioctl_write_ptr!(ca_send_msg, IOCTL_TYPE, CA_SEND_MSG, CaMsg);
@@ -0,0 +1,7 @@
+pub mod data;
+#[cfg(feature = "std")]
+pub mod functions;
+#[cfg(feature = "std")]
+pub mod ioctl;
+#[cfg(feature = "std")]
+pub mod wrapper;
@@ -0,0 +1,85 @@
+//! Owned handle on a Conditional Access device, on top of the raw functions
+
+use std::{
+    ffi::c_int,
+    fs::OpenOptions,
+    io,
+    os::{
+        fd::{AsFd, BorrowedFd, OwnedFd},
+        unix::fs::OpenOptionsExt as _,
+    },
+    path::Path,
+};
+
+use nix::errno::Errno;
+
+use crate::{
+    ca::{
+        data::{CaCaps, CaMsg, CaSlotInfo},
+        functions::{get_cap, get_msg, get_slot_info, reset, send_msg},
+    },
+    fd::nonblocking_flag,
+};
+
+/// An opened Conditional Access device, such as `/dev/dvb/adapter0/ca0`.
+#[derive(Debug)]
+pub struct Ca {
+    fd: OwnedFd,
+}
+
+impl Ca {
+    /// Opens the CA device at given path for reading and writing.
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open(path: impl AsRef<Path>, nonblocking: bool) -> io::Result<Ca> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(nonblocking_flag(nonblocking))
+            .open(path)?;
+        Ok(Ca { fd: file.into() })
+    }
+
+    /// Resets the CA hardware, see [reset].
+    pub fn reset(&self) -> Result<(), Errno> {
+        reset(self.fd.as_fd())
+    }
+
+    /// Queries the available slots and descramblers, see [get_cap].
+    pub fn caps(&self) -> Result<CaCaps, Errno> {
+        get_cap(self.fd.as_fd())
+    }
+
+    /// Queries the type and state of a slot, see [get_slot_info].
+    pub fn slot_info(&self, slot: c_int) -> Result<CaSlotInfo, Errno> {
+        get_slot_info(self.fd.as_fd(), slot)
+    }
+
+    /// Receives a message from the module, see [get_msg].
+    pub fn get_msg(&self) -> Result<CaMsg, Errno> {
+        get_msg(self.fd.as_fd())
+    }
+
+    /// Sends a message to the module, see [send_msg].
+    pub fn send_msg(&self, msg: &CaMsg) -> Result<(), Errno> {
+        send_msg(self.fd.as_fd(), msg)
+    }
+}
+
+impl AsFd for Ca {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<OwnedFd> for Ca {
+    fn from(fd: OwnedFd) -> Self {
+        Ca { fd }
+    }
+}
+
+impl From<Ca> for OwnedFd {
+    fn from(ca: Ca) -> Self {
+        ca.fd
+    }
+}
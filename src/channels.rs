@@ -0,0 +1,67 @@
+//! Terrestrial channel numbers, as printed on TV sets and broadcaster websites
+
+use core::ops::RangeInclusive;
+
+/// Frequency plan used to number terrestrial channels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Europe and most of Africa and Asia: 8 MHz channels, UHF 21 to 69.
+    Europe,
+    /// United States, Canada, Mexico and South Korea: 6 MHz channels, UHF 14 to 36.
+    NorthAmerica,
+    /// Japan (ISDB-T): 6 MHz channels, UHF 13 to 62.
+    Japan,
+    /// Brazil and the rest of South America using ISDB-Tb: 6 MHz channels, UHF 14 to 69.
+    Brazil,
+}
+
+impl Region {
+    /// UHF channel numbers in use in this region.
+    pub fn uhf_channels(self) -> RangeInclusive<u8> {
+        match self {
+            Region::Europe => 21..=69,
+            Region::NorthAmerica => 14..=36,
+            Region::Japan => 13..=62,
+            Region::Brazil => 14..=69,
+        }
+    }
+
+    /// Width of a channel, in Hz.
+    pub fn channel_width_hz(self) -> u32 {
+        match self {
+            Region::Europe => 8_000_000,
+            Region::NorthAmerica | Region::Japan | Region::Brazil => 6_000_000,
+        }
+    }
+
+    /// Center frequency of the first UHF channel, in Hz, without any offset.
+    fn first_uhf_center_hz(self) -> u32 {
+        match self {
+            Region::Europe => 474_000_000,
+            Region::NorthAmerica | Region::Japan | Region::Brazil => 473_000_000,
+        }
+    }
+
+    /// ISDB-T channels are shifted up by 1/7 MHz, see [DTV_FREQUENCY](crate::frontend::property::Command::DTV_FREQUENCY).
+    fn offset_hz(self) -> u32 {
+        match self {
+            Region::Europe | Region::NorthAmerica => 0,
+            Region::Japan | Region::Brazil => 1_000_000 / 7,
+        }
+    }
+}
+
+/// Frequency to tune to for a UHF channel number, in Hz.
+///
+/// For instance, channel 21 in Europe is 474 MHz. ISDB-T regions include the 1/7 MHz offset.
+///
+/// Returns [None] if the channel is not a UHF channel of the region, see [Region::uhf_channels].
+pub fn uhf_center_hz(channel: u8, region: Region) -> Option<u32> {
+    let channels = region.uhf_channels();
+    if !channels.contains(&channel) {
+        return None;
+    }
+
+    let index = u32::from(channel - channels.start());
+    Some(region.first_uhf_center_hz() + index * region.channel_width_hz() + region.offset_hz())
+}
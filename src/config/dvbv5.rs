@@ -0,0 +1,227 @@
+//! Parsing of channel files written by `dvbv5-scan` (`dvb_channel.conf`)
+//!
+//! Each channel is an INI-like section, with keys named after DTV properties:
+//! ```text
+//! [Channel Name]
+//!     DELIVERY_SYSTEM = DVBT
+//!     FREQUENCY = 490000000
+//!     BANDWIDTH_HZ = 8000000
+//!     MODULATION = QAM/64
+//! ```
+
+use crate::{
+    error::Dvbv5ParseError,
+    frontend::{
+        data::{
+            FeCodeRate, FeDeliverySystem, FeGuardInterval, FeHierarchy, FePilot, FeRolloff,
+            FeSpectralInversion, FeTransmitMode,
+        },
+        property::DtvProperty,
+        queries::set::{
+            BandwidthHz, CodeRateHp, CodeRateLp, DeliverySystem, Frequency, GuardInterval,
+            Hierarchy, InnerFec, Inversion, Modulation, Pilot, Rolloff, SetPropertyQuery, StreamId,
+            SymbolRate, TransmissionMode, Tune,
+        },
+    },
+};
+
+/// Parses all channels of a dvbv5 channel file.
+///
+/// For each channel, properties are returned in a ready to send order:
+/// the delivery system first, then all other parameters, ending with ``DTV_TUNE``.
+/// Keys that are not tuning properties (PIDs, service ID...) are ignored.
+///
+/// Satellite channels are rejected with [Dvbv5ParseError::Satellite]: their frequency is the one of the transponder,
+/// which only becomes tunable through the LNB, see [DvbsTuneRequest](crate::frontend::queries::set::DvbsTuneRequest).
+pub fn parse_channels(content: &str) -> Result<Vec<(String, Vec<DtvProperty>)>, Dvbv5ParseError> {
+    let mut channels: Vec<(String, Vec<DtvProperty>)> = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            channels.push((name.to_string(), Vec::new()));
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| Dvbv5ParseError::MalformedLine(line.to_string()))?;
+        let (key, value) = (key.trim(), value.trim());
+        let (name, properties) = channels
+            .last_mut()
+            .ok_or_else(|| Dvbv5ParseError::OutsideChannel(line.to_string()))?;
+
+        if key == "DELIVERY_SYSTEM"
+            && let Ok(system) = value.parse::<FeDeliverySystem>()
+            && system.is_satellite()
+        {
+            return Err(Dvbv5ParseError::Satellite {
+                channel: name.clone(),
+                system,
+            });
+        }
+
+        let Some(property) = parse_property(key, value)? else {
+            continue;
+        };
+        if key == "DELIVERY_SYSTEM" {
+            properties.insert(0, property);
+        } else {
+            properties.push(property);
+        }
+    }
+
+    for (_, properties) in channels.iter_mut() {
+        properties.push(Tune {}.property());
+    }
+
+    Ok(channels)
+}
+
+/// Turns a key and its value into a property, if the key is a known tuning property.
+fn parse_property(key: &str, value: &str) -> Result<Option<DtvProperty>, Dvbv5ParseError> {
+    let invalid = || Dvbv5ParseError::InvalidValue(key.to_string(), value.to_string());
+    let number = || value.parse::<u32>().map_err(|_| invalid());
+
+    Ok(Some(match key {
+        "DELIVERY_SYSTEM" => DeliverySystem::new(value.parse().map_err(|_| invalid())?).property(),
+        "FREQUENCY" => Frequency::new(number()?).property(),
+        "SYMBOL_RATE" => SymbolRate::new(number()?).property(),
+        "BANDWIDTH_HZ" => BandwidthHz::from_hz(number()?)
+            .ok_or_else(invalid)?
+            .property(),
+        "MODULATION" => Modulation::new(value.parse().map_err(|_| invalid())?).property(),
+        "INVERSION" => Inversion::new(inversion(value).ok_or_else(invalid)?).property(),
+        "INNER_FEC" => InnerFec::new(code_rate(value).ok_or_else(invalid)?).property(),
+        "CODE_RATE_HP" => CodeRateHp::new(code_rate(value).ok_or_else(invalid)?).property(),
+        "CODE_RATE_LP" => CodeRateLp::new(code_rate(value).ok_or_else(invalid)?).property(),
+        "GUARD_INTERVAL" => {
+            GuardInterval::new(guard_interval(value).ok_or_else(invalid)?).property()
+        }
+        "TRANSMISSION_MODE" => {
+            TransmissionMode::new(transmission_mode(value).ok_or_else(invalid)?).property()
+        }
+        "HIERARCHY" => Hierarchy::new(hierarchy(value).ok_or_else(invalid)?).property(),
+        "PILOT" => Pilot::new(pilot(value).ok_or_else(invalid)?).property(),
+        "ROLLOFF" => Rolloff::new(rolloff(value).ok_or_else(invalid)?).property(),
+        "STREAM_ID" => StreamId::new(number()?).property(),
+        _ => return Ok(None),
+    }))
+}
+
+fn inversion(value: &str) -> Option<FeSpectralInversion> {
+    Some(match value {
+        "OFF" => FeSpectralInversion::INVERSION_OFF,
+        "ON" => FeSpectralInversion::INVERSION_ON,
+        "AUTO" => FeSpectralInversion::INVERSION_AUTO,
+        _ => return None,
+    })
+}
+
+fn code_rate(value: &str) -> Option<FeCodeRate> {
+    Some(match value {
+        "NONE" => FeCodeRate::FEC_NONE,
+        "1/2" => FeCodeRate::FEC_1_2,
+        "2/3" => FeCodeRate::FEC_2_3,
+        "3/4" => FeCodeRate::FEC_3_4,
+        "4/5" => FeCodeRate::FEC_4_5,
+        "5/6" => FeCodeRate::FEC_5_6,
+        "6/7" => FeCodeRate::FEC_6_7,
+        "7/8" => FeCodeRate::FEC_7_8,
+        "8/9" => FeCodeRate::FEC_8_9,
+        "AUTO" => FeCodeRate::FEC_AUTO,
+        "3/5" => FeCodeRate::FEC_3_5,
+        "9/10" => FeCodeRate::FEC_9_10,
+        "2/5" => FeCodeRate::FEC_2_5,
+        "1/3" => FeCodeRate::FEC_1_3,
+        "1/4" => FeCodeRate::FEC_1_4,
+        "5/9" => FeCodeRate::FEC_5_9,
+        "7/9" => FeCodeRate::FEC_7_9,
+        "8/15" => FeCodeRate::FEC_8_15,
+        "11/15" => FeCodeRate::FEC_11_15,
+        "13/18" => FeCodeRate::FEC_13_18,
+        "9/20" => FeCodeRate::FEC_9_20,
+        "11/20" => FeCodeRate::FEC_11_20,
+        "23/36" => FeCodeRate::FEC_23_36,
+        "25/36" => FeCodeRate::FEC_25_36,
+        "13/45" => FeCodeRate::FEC_13_45,
+        "26/45" => FeCodeRate::FEC_26_45,
+        "28/45" => FeCodeRate::FEC_28_45,
+        "32/45" => FeCodeRate::FEC_32_45,
+        "77/90" => FeCodeRate::FEC_77_90,
+        "11/45" => FeCodeRate::FEC_11_45,
+        "4/15" => FeCodeRate::FEC_4_15,
+        "14/45" => FeCodeRate::FEC_14_45,
+        "7/15" => FeCodeRate::FEC_7_15,
+        _ => return None,
+    })
+}
+
+fn guard_interval(value: &str) -> Option<FeGuardInterval> {
+    Some(match value {
+        "1/32" => FeGuardInterval::GUARD_INTERVAL_1_32,
+        "1/16" => FeGuardInterval::GUARD_INTERVAL_1_16,
+        "1/8" => FeGuardInterval::GUARD_INTERVAL_1_8,
+        "1/4" => FeGuardInterval::GUARD_INTERVAL_1_4,
+        "AUTO" => FeGuardInterval::GUARD_INTERVAL_AUTO,
+        "1/128" => FeGuardInterval::GUARD_INTERVAL_1_128,
+        "19/128" => FeGuardInterval::GUARD_INTERVAL_19_128,
+        "19/256" => FeGuardInterval::GUARD_INTERVAL_19_256,
+        "PN420" => FeGuardInterval::GUARD_INTERVAL_PN420,
+        "PN595" => FeGuardInterval::GUARD_INTERVAL_PN595,
+        "PN945" => FeGuardInterval::GUARD_INTERVAL_PN945,
+        "1/64" => FeGuardInterval::GUARD_INTERVAL_1_64,
+        _ => return None,
+    })
+}
+
+fn transmission_mode(value: &str) -> Option<FeTransmitMode> {
+    Some(match value {
+        "2K" => FeTransmitMode::TRANSMISSION_MODE_2K,
+        "8K" => FeTransmitMode::TRANSMISSION_MODE_8K,
+        "AUTO" => FeTransmitMode::TRANSMISSION_MODE_AUTO,
+        "4K" => FeTransmitMode::TRANSMISSION_MODE_4K,
+        "1K" => FeTransmitMode::TRANSMISSION_MODE_1K,
+        "16K" => FeTransmitMode::TRANSMISSION_MODE_16K,
+        "32K" => FeTransmitMode::TRANSMISSION_MODE_32K,
+        "C1" => FeTransmitMode::TRANSMISSION_MODE_C1,
+        "C3780" => FeTransmitMode::TRANSMISSION_MODE_C3780,
+        _ => return None,
+    })
+}
+
+fn hierarchy(value: &str) -> Option<FeHierarchy> {
+    Some(match value {
+        "NONE" => FeHierarchy::HIERARCHY_NONE,
+        "1" => FeHierarchy::HIERARCHY_1,
+        "2" => FeHierarchy::HIERARCHY_2,
+        "4" => FeHierarchy::HIERARCHY_4,
+        "AUTO" => FeHierarchy::HIERARCHY_AUTO,
+        _ => return None,
+    })
+}
+
+fn pilot(value: &str) -> Option<FePilot> {
+    Some(match value {
+        "ON" => FePilot::PILOT_ON,
+        "OFF" => FePilot::PILOT_OFF,
+        "AUTO" => FePilot::PILOT_AUTO,
+        _ => return None,
+    })
+}
+
+fn rolloff(value: &str) -> Option<FeRolloff> {
+    Some(match value {
+        "35" => FeRolloff::ROLLOFF_35,
+        "20" => FeRolloff::ROLLOFF_20,
+        "25" => FeRolloff::ROLLOFF_25,
+        "AUTO" => FeRolloff::ROLLOFF_AUTO,
+        "15" => FeRolloff::ROLLOFF_15,
+        "10" => FeRolloff::ROLLOFF_10,
+        "5" => FeRolloff::ROLLOFF_5,
+        _ => return None,
+    })
+}
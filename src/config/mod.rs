@@ -0,0 +1,2 @@
+pub mod dvbv5;
+pub mod vdr;
@@ -0,0 +1,453 @@
+//! Parsing of VDR-style `channels.conf` files
+//!
+//! Each line describes a channel with `:`-separated fields:
+//! `Name;Provider:Frequency:Parameters:Source:Srate:VPID:APID:TPID:CAID:SID:NID:TID:RID`
+//!
+//! (format described in [vdr(5)](https://www.tvdr.de/))
+
+use std::str::FromStr;
+
+use crate::{
+    error::VdrParseError,
+    frontend::{
+        data::{
+            FeCodeRate, FeDeliverySystem, FeGuardInterval, FeHierarchy, FeModulation, FePilot,
+            FeRolloff, FeSpectralInversion, FeTransmitMode,
+        },
+        lnb::{Lnb, Polarization},
+        queries::set::{BandwidthHz, DvbsTuneRequest, DvbtTuneRequest},
+    },
+};
+
+/// Value used by VDR for parameters that should be detected automatically.
+const AUTO: u32 = 999;
+
+/// Where a channel is received from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Satellite, with its orbital position as written in the file (e.g. `19.2E`)
+    Satellite(String),
+    Terrestrial,
+    Cable,
+    Atsc,
+}
+
+/// Transmission parameters, from the parameter string (e.g. `B8C23D12G32M64T8Y0S0`).
+///
+/// Parameters that are not present in the string are `None`.
+#[derive(Debug, Clone, Default)]
+pub struct Parameters {
+    pub bandwidth: Option<BandwidthHz>,
+    pub code_rate_hp: Option<FeCodeRate>,
+    pub code_rate_lp: Option<FeCodeRate>,
+    pub guard_interval: Option<FeGuardInterval>,
+    pub inversion: Option<FeSpectralInversion>,
+    pub modulation: Option<FeModulation>,
+    pub pilot: Option<FePilot>,
+    pub rolloff: Option<FeRolloff>,
+    pub stream_id: Option<u32>,
+    pub transmission_mode: Option<FeTransmitMode>,
+    pub hierarchy: Option<FeHierarchy>,
+    pub polarization: Option<Polarization>,
+    /// Set if the second generation of the system (DVB-S2, DVB-T2) is used
+    pub second_generation: bool,
+}
+
+/// A channel, as described by a line of `channels.conf`.
+#[derive(Debug, Clone)]
+pub struct Channel {
+    pub name: String,
+    pub provider: String,
+    pub source: Source,
+    pub delivery_system: FeDeliverySystem,
+    /// Frequency in the unit expected by the kernel: kHz for satellite, Hz otherwise.
+    pub frequency: u32,
+    /// Symbol rate, in symbols per second.
+    pub symbol_rate: u32,
+    pub parameters: Parameters,
+    pub video_pid: u16,
+    pub pcr_pid: Option<u16>,
+    pub audio_pids: Vec<u16>,
+    pub dolby_pids: Vec<u16>,
+    pub teletext_pid: u16,
+    /// Conditional access system IDs, empty for free-to-air channels.
+    pub ca_ids: Vec<u16>,
+    pub service_id: u16,
+    pub network_id: u16,
+    pub transport_stream_id: u16,
+    pub radio_id: u16,
+}
+
+impl Channel {
+    /// Polarization of the transponder, for satellite channels.
+    pub fn polarization(&self) -> Option<Polarization> {
+        self.parameters.polarization
+    }
+
+    /// Creates a tune request for a DVB-T or DVB-T2 channel.
+    pub fn dvbt_request(&self) -> Option<DvbtTuneRequest> {
+        if self.source != Source::Terrestrial {
+            return None;
+        }
+
+        let p = &self.parameters;
+        let mut request = DvbtTuneRequest::new().frequency(self.frequency);
+        if p.second_generation {
+            request = request.dvbt2();
+        }
+        if let Some(bandwidth) = p.bandwidth {
+            request = request.bandwidth(bandwidth);
+        }
+        if let Some(rate) = p.code_rate_hp {
+            request = request.code_rate_hp(rate);
+        }
+        if let Some(rate) = p.code_rate_lp {
+            request = request.code_rate_lp(rate);
+        }
+        if let Some(modulation) = p.modulation {
+            request = request.modulation(modulation);
+        }
+        if let Some(mode) = p.transmission_mode {
+            request = request.transmission_mode(mode);
+        }
+        if let Some(interval) = p.guard_interval {
+            request = request.guard_interval(interval);
+        }
+        if let Some(hierarchy) = p.hierarchy {
+            request = request.hierarchy(hierarchy);
+        }
+        if let Some(inversion) = p.inversion {
+            request = request.inversion(inversion);
+        }
+        if let Some(id) = p.stream_id {
+            request = request.stream_id(id);
+        }
+        Some(request)
+    }
+
+    /// Creates a tune request for a DVB-S or DVB-S2 channel, received through given LNB.
+    pub fn dvbs_request(&self, lnb: Lnb) -> Option<DvbsTuneRequest> {
+        if !matches!(self.source, Source::Satellite(_)) {
+            return None;
+        }
+
+        let p = &self.parameters;
+        let mut request = DvbsTuneRequest::new(lnb)
+            .frequency(self.frequency)
+            .symbol_rate(self.symbol_rate);
+        if p.second_generation {
+            request = request.dvbs2();
+        }
+        if let Some(polarization) = p.polarization {
+            request = request.polarization(polarization);
+        }
+        if let Some(fec) = p.code_rate_hp {
+            request = request.fec(fec);
+        }
+        if let Some(modulation) = p.modulation {
+            request = request.modulation(modulation);
+        }
+        if let Some(rolloff) = p.rolloff {
+            request = request.rolloff(rolloff);
+        }
+        if let Some(pilot) = p.pilot {
+            request = request.pilot(pilot);
+        }
+        if let Some(inversion) = p.inversion {
+            request = request.inversion(inversion);
+        }
+        Some(request)
+    }
+}
+
+impl FromStr for Channel {
+    type Err = VdrParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.trim().split(':').collect();
+        if fields.len() < 13 {
+            return Err(VdrParseError::FieldCount(fields.len()));
+        }
+
+        // Name can be "name,short name;provider", with ':' escaped as '|'
+        let (name, provider) = fields[0].split_once(';').unwrap_or((fields[0], ""));
+        let name = name.split(',').next().unwrap_or(name).replace('|', ":");
+        let provider = provider.replace('|', ":");
+
+        let parameters = parse_parameters(fields[2])?;
+        let source = match fields[3].chars().next() {
+            Some('S') => Source::Satellite(fields[3][1..].to_string()),
+            Some('T') => Source::Terrestrial,
+            Some('C') => Source::Cable,
+            Some('A') => Source::Atsc,
+            _ => return Err(VdrParseError::UnsupportedSource(fields[3].to_string())),
+        };
+        let delivery_system = match (&source, parameters.second_generation) {
+            (Source::Satellite(_), false) => FeDeliverySystem::DVBS,
+            (Source::Satellite(_), true) => FeDeliverySystem::DVBS2,
+            (Source::Terrestrial, false) => FeDeliverySystem::DVBT,
+            (Source::Terrestrial, true) => FeDeliverySystem::DVBT2,
+            (Source::Cable, _) => FeDeliverySystem::DVBC_ANNEX_A,
+            (Source::Atsc, _) => FeDeliverySystem::ATSC,
+        };
+
+        let frequency = parse_number::<u64>("frequency", fields[1])?;
+        let frequency = match source {
+            // Always in MHz for satellites
+            Source::Satellite(_) => frequency.checked_mul(1_000),
+            // Either MHz, kHz or Hz otherwise
+            _ if frequency < 1_000 => frequency.checked_mul(1_000_000),
+            _ if frequency < 1_000_000 => frequency.checked_mul(1_000),
+            _ => Some(frequency),
+        };
+        let frequency = frequency
+            .and_then(|frequency| u32::try_from(frequency).ok())
+            .ok_or_else(|| VdrParseError::InvalidNumber("frequency", fields[1].to_string()))?;
+
+        // kSymbols/s, a rate written in symbols/s does not fit once scaled
+        let symbol_rate = parse_number::<u32>("symbol rate", fields[4])?
+            .checked_mul(1_000)
+            .ok_or_else(|| VdrParseError::InvalidNumber("symbol rate", fields[4].to_string()))?;
+
+        // VPID can be "vpid+pcr=stream type"
+        let (video, _stream_type) = fields[5].split_once('=').unwrap_or((fields[5], ""));
+        let (video, pcr) = match video.split_once('+') {
+            Some((video, pcr)) => (video, Some(parse_number("PCR PID", pcr)?)),
+            None => (video, None),
+        };
+        let video_pid = parse_number("video PID", video)?;
+
+        // APID is "a1=lang,a2=lang;d1=lang,d2=lang"
+        let (audio, dolby) = fields[6].split_once(';').unwrap_or((fields[6], ""));
+        let audio_pids = parse_pid_list("audio PID", audio)?;
+        let dolby_pids = parse_pid_list("dolby PID", dolby)?;
+
+        // TPID can be followed by subtitle PIDs
+        let teletext = fields[7].split(';').next().unwrap_or_default();
+        let teletext_pid = parse_number("teletext PID", teletext)?;
+
+        let ca_ids = fields[8]
+            .split(',')
+            .filter(|id| !id.is_empty() && *id != "0")
+            .map(|id| {
+                u16::from_str_radix(id, 16)
+                    .map_err(|_| VdrParseError::InvalidNumber("CA ID", id.to_string()))
+            })
+            .collect::<Result<Vec<u16>, VdrParseError>>()?;
+
+        Ok(Channel {
+            name,
+            provider,
+            source,
+            delivery_system,
+            frequency,
+            symbol_rate,
+            parameters,
+            video_pid,
+            pcr_pid: pcr,
+            audio_pids,
+            dolby_pids,
+            teletext_pid,
+            ca_ids,
+            service_id: parse_number("SID", fields[9])?,
+            network_id: parse_number("NID", fields[10])?,
+            transport_stream_id: parse_number("TID", fields[11])?,
+            radio_id: parse_number("RID", fields[12])?,
+        })
+    }
+}
+
+/// Parses all channels of a `channels.conf` file.
+///
+/// Empty lines and group separators (starting with `:`) are skipped.
+pub fn parse_channels(content: &str) -> Result<Vec<Channel>, VdrParseError> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(':'))
+        .map(Channel::from_str)
+        .collect()
+}
+
+fn parse_number<T: FromStr>(field: &'static str, value: &str) -> Result<T, VdrParseError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| VdrParseError::InvalidNumber(field, value.to_string()))
+}
+
+/// Parses "pid=lang@type,pid=lang" lists, keeping only the PIDs.
+fn parse_pid_list(field: &'static str, value: &str) -> Result<Vec<u16>, VdrParseError> {
+    value
+        .split(',')
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let pid = entry.split(['=', '@']).next().unwrap_or(entry);
+            parse_number(field, pid)
+        })
+        .collect()
+}
+
+/// Parses the parameter string, made of letters each followed by an optional number.
+fn parse_parameters(value: &str) -> Result<Parameters, VdrParseError> {
+    let mut parameters = Parameters::default();
+
+    let mut chars = value.chars().peekable();
+    while let Some(letter) = chars.next() {
+        let mut digits = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            digits.push(digit);
+        }
+        let number = || -> Result<u32, VdrParseError> {
+            digits
+                .parse()
+                .map_err(|_| VdrParseError::InvalidParameter(letter, digits.clone()))
+        };
+        let invalid = || VdrParseError::InvalidParameter(letter, digits.clone());
+
+        match letter.to_ascii_uppercase() {
+            'B' => parameters.bandwidth = Some(bandwidth(number()?).ok_or_else(invalid)?),
+            'C' => parameters.code_rate_hp = Some(code_rate(number()?).ok_or_else(invalid)?),
+            'D' => parameters.code_rate_lp = Some(code_rate(number()?).ok_or_else(invalid)?),
+            'G' => parameters.guard_interval = Some(guard_interval(number()?).ok_or_else(invalid)?),
+            'H' => parameters.polarization = Some(Polarization::Horizontal),
+            'I' => parameters.inversion = Some(inversion(number()?).ok_or_else(invalid)?),
+            'L' => parameters.polarization = Some(Polarization::CircularLeft),
+            'M' => parameters.modulation = Some(modulation(number()?).ok_or_else(invalid)?),
+            'N' => parameters.pilot = Some(pilot(number()?).ok_or_else(invalid)?),
+            'O' => parameters.rolloff = Some(rolloff(number()?).ok_or_else(invalid)?),
+            'P' => parameters.stream_id = Some(number()?),
+            'R' => parameters.polarization = Some(Polarization::CircularRight),
+            'S' => parameters.second_generation = number()? == 1,
+            'T' => {
+                parameters.transmission_mode =
+                    Some(transmission_mode(number()?).ok_or_else(invalid)?)
+            }
+            'V' => parameters.polarization = Some(Polarization::Vertical),
+            'Y' => parameters.hierarchy = Some(hierarchy(number()?).ok_or_else(invalid)?),
+            // Other parameters (T2 system id, SISO/MISO...) are not used for tuning
+            _ => {}
+        }
+    }
+
+    Ok(parameters)
+}
+
+fn bandwidth(value: u32) -> Option<BandwidthHz> {
+    Some(match value {
+        1712 => BandwidthHz::_1_172MHz,
+        5 => BandwidthHz::_5MHz,
+        6 => BandwidthHz::_6MHz,
+        7 => BandwidthHz::_7MHz,
+        8 => BandwidthHz::_8MHz,
+        10 => BandwidthHz::_10MHz,
+        _ => return None,
+    })
+}
+
+fn code_rate(value: u32) -> Option<FeCodeRate> {
+    Some(match value {
+        0 => FeCodeRate::FEC_NONE,
+        12 => FeCodeRate::FEC_1_2,
+        23 => FeCodeRate::FEC_2_3,
+        34 => FeCodeRate::FEC_3_4,
+        35 => FeCodeRate::FEC_3_5,
+        45 => FeCodeRate::FEC_4_5,
+        56 => FeCodeRate::FEC_5_6,
+        67 => FeCodeRate::FEC_6_7,
+        78 => FeCodeRate::FEC_7_8,
+        89 => FeCodeRate::FEC_8_9,
+        910 => FeCodeRate::FEC_9_10,
+        25 => FeCodeRate::FEC_2_5,
+        AUTO => FeCodeRate::FEC_AUTO,
+        _ => return None,
+    })
+}
+
+fn guard_interval(value: u32) -> Option<FeGuardInterval> {
+    Some(match value {
+        4 => FeGuardInterval::GUARD_INTERVAL_1_4,
+        8 => FeGuardInterval::GUARD_INTERVAL_1_8,
+        16 => FeGuardInterval::GUARD_INTERVAL_1_16,
+        32 => FeGuardInterval::GUARD_INTERVAL_1_32,
+        128 => FeGuardInterval::GUARD_INTERVAL_1_128,
+        19128 => FeGuardInterval::GUARD_INTERVAL_19_128,
+        19256 => FeGuardInterval::GUARD_INTERVAL_19_256,
+        AUTO => FeGuardInterval::GUARD_INTERVAL_AUTO,
+        _ => return None,
+    })
+}
+
+fn inversion(value: u32) -> Option<FeSpectralInversion> {
+    Some(match value {
+        0 => FeSpectralInversion::INVERSION_OFF,
+        1 => FeSpectralInversion::INVERSION_ON,
+        AUTO => FeSpectralInversion::INVERSION_AUTO,
+        _ => return None,
+    })
+}
+
+fn modulation(value: u32) -> Option<FeModulation> {
+    Some(match value {
+        2 => FeModulation::QPSK,
+        5 => FeModulation::PSK_8,
+        6 => FeModulation::APSK_16,
+        7 => FeModulation::APSK_32,
+        10 => FeModulation::VSB_8,
+        11 => FeModulation::VSB_16,
+        12 => FeModulation::DQPSK,
+        16 => FeModulation::QAM_16,
+        32 => FeModulation::QAM_32,
+        64 => FeModulation::QAM_64,
+        128 => FeModulation::QAM_128,
+        256 => FeModulation::QAM_256,
+        AUTO => FeModulation::QAM_AUTO,
+        _ => return None,
+    })
+}
+
+fn pilot(value: u32) -> Option<FePilot> {
+    Some(match value {
+        0 => FePilot::PILOT_OFF,
+        1 => FePilot::PILOT_ON,
+        AUTO => FePilot::PILOT_AUTO,
+        _ => return None,
+    })
+}
+
+fn rolloff(value: u32) -> Option<FeRolloff> {
+    Some(match value {
+        0 => FeRolloff::ROLLOFF_AUTO,
+        5 => FeRolloff::ROLLOFF_5,
+        10 => FeRolloff::ROLLOFF_10,
+        15 => FeRolloff::ROLLOFF_15,
+        20 => FeRolloff::ROLLOFF_20,
+        25 => FeRolloff::ROLLOFF_25,
+        35 => FeRolloff::ROLLOFF_35,
+        _ => return None,
+    })
+}
+
+fn transmission_mode(value: u32) -> Option<FeTransmitMode> {
+    Some(match value {
+        1 => FeTransmitMode::TRANSMISSION_MODE_1K,
+        2 => FeTransmitMode::TRANSMISSION_MODE_2K,
+        4 => FeTransmitMode::TRANSMISSION_MODE_4K,
+        8 => FeTransmitMode::TRANSMISSION_MODE_8K,
+        16 => FeTransmitMode::TRANSMISSION_MODE_16K,
+        32 => FeTransmitMode::TRANSMISSION_MODE_32K,
+        AUTO => FeTransmitMode::TRANSMISSION_MODE_AUTO,
+        _ => return None,
+    })
+}
+
+fn hierarchy(value: u32) -> Option<FeHierarchy> {
+    Some(match value {
+        0 => FeHierarchy::HIERARCHY_NONE,
+        1 => FeHierarchy::HIERARCHY_1,
+        2 => FeHierarchy::HIERARCHY_2,
+        4 => FeHierarchy::HIERARCHY_4,
+        AUTO => FeHierarchy::HIERARCHY_AUTO,
+        _ => return None,
+    })
+}
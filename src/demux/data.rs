@@ -1,7 +1,17 @@
-use std::ffi::c_uint;
+use core::ffi::c_uint;
 
 pub const DMX_FILTER_SIZE: usize = 16;
 
+/// Special PID value making a PES filter let through every packet of the transport stream.
+pub const DMX_PID_ALL: u16 = 0x2000;
+
+/// Section filter flag: only deliver sections where the CRC check succeeded.
+pub const DMX_CHECK_CRC: u32 = 0x01;
+/// Section filter flag: disable the section filter after one section has been delivered.
+pub const DMX_ONESHOT: u32 = 0x02;
+/// Filter flag: start filter immediately without requiring a DMX_START.
+pub const DMX_IMMEDIATE_START: u32 = 0x04;
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[allow(non_camel_case_types)]
@@ -75,7 +85,7 @@ impl DmxFilter {
 ///
 /// Carries the configuration for a MPEG-TS section filter.
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub struct DmxSctFilterParams {
     /// PID to be filtered.
     pub pid: u16,
@@ -88,6 +98,62 @@ pub struct DmxSctFilterParams {
     pub flags: u32,
 }
 
+/// Builds [DmxSctFilterParams] without dealing with the filter byte layout.
+///
+/// By default, sections from PID 0 are delivered without timeout or flags.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SectionFilter {
+    params: DmxSctFilterParams,
+}
+
+impl SectionFilter {
+    pub fn new() -> SectionFilter {
+        SectionFilter::default()
+    }
+
+    /// PID carrying the sections.
+    pub fn pid(mut self, pid: u16) -> Self {
+        self.params.pid = pid;
+        self
+    }
+
+    /// Only lets through sections with given table id, such as 0x00 for the PAT or 0x02 for a PMT.
+    pub fn table_id(mut self, table_id: u8) -> Self {
+        self.params.filter.first_byte_mask(table_id);
+        self
+    }
+
+    /// Maximum time to filter, in milliseconds. 0 means no timeout.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.params.timeout = timeout;
+        self
+    }
+
+    /// Only delivers sections with a valid CRC, see [DMX_CHECK_CRC].
+    pub fn check_crc(self) -> Self {
+        self.flag(DMX_CHECK_CRC)
+    }
+
+    /// Stops after the first section, see [DMX_ONESHOT].
+    pub fn oneshot(self) -> Self {
+        self.flag(DMX_ONESHOT)
+    }
+
+    /// Starts filtering as soon as the filter is set, see [DMX_IMMEDIATE_START].
+    pub fn immediate_start(self) -> Self {
+        self.flag(DMX_IMMEDIATE_START)
+    }
+
+    fn flag(mut self, flag: u32) -> Self {
+        self.params.flags |= flag;
+        self
+    }
+
+    pub fn build(self) -> DmxSctFilterParams {
+        self.params
+    }
+}
+
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx_types.html#c.dmx_pes_filter_params))
 ///
 /// Specifies Packetized Elementary Stream (PES) filter parameters.
@@ -107,11 +173,44 @@ pub struct DmxPesFilterParams {
     pub flags: u32,
 }
 
+impl DmxPesFilterParams {
+    /// Filter on a video PID from the frontend, sent to the DVR device and started right away.
+    pub fn video(pid: u16) -> DmxPesFilterParams {
+        DmxPesFilterParams::to_dvr(pid, DmxTsPes::DMX_PES_VIDEO0)
+    }
+
+    /// Filter on an audio PID from the frontend, sent to the DVR device and started right away.
+    pub fn audio(pid: u16) -> DmxPesFilterParams {
+        DmxPesFilterParams::to_dvr(pid, DmxTsPes::DMX_PES_AUDIO0)
+    }
+
+    /// Filter on a PCR PID from the frontend, sent to the DVR device and started right away.
+    pub fn pcr(pid: u16) -> DmxPesFilterParams {
+        DmxPesFilterParams::to_dvr(pid, DmxTsPes::DMX_PES_PCR0)
+    }
+
+    /// Filter on any other PID from the frontend, sent to the DVR device and started right away.
+    pub fn other(pid: u16) -> DmxPesFilterParams {
+        DmxPesFilterParams::to_dvr(pid, DmxTsPes::DMX_PES_OTHER)
+    }
+
+    fn to_dvr(pid: u16, pes_type: DmxTsPes) -> DmxPesFilterParams {
+        DmxPesFilterParams {
+            pid,
+            input: DmxInput::DMX_IN_FRONTEND,
+            output: DmxOutput::DMX_OUT_TS_TAP,
+            pes_type,
+            flags: DMX_IMMEDIATE_START,
+        }
+    }
+}
+
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx_types.html#c.dmx_stc))
 ///
 /// Stores System Time Counter (STC) information.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct DmxStc {
     /// input data: number of the STC, from 0 to N.
     pub num: c_uint,
@@ -120,3 +219,12 @@ pub struct DmxStc {
     /// output: stc in **base** * 90 kHz units.
     pub stc: u64,
 }
+
+impl DmxStc {
+    /// Value of the counter in 90 kHz units, as used by PTS and DTS timestamps.
+    ///
+    /// A base of 0 is treated as 1, which is what drivers mean when they leave it unset.
+    pub fn as_90khz(&self) -> u64 {
+        self.stc / u64::from(self.base.max(1))
+    }
+}
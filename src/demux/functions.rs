@@ -1,12 +1,16 @@
-use std::os::fd::{AsRawFd as _, BorrowedFd};
+use std::{
+    ffi::c_int,
+    os::fd::{AsRawFd as _, BorrowedFd},
+};
 
 use nix::errno::Errno;
 
 use crate::{
     demux::{
-        data::{DmxPesFilterParams, DmxSctFilterParams},
+        data::{DmxPesFilterParams, DmxSctFilterParams, DmxStc},
         ioctl::{
-            dmx_add_pid, dmx_remove_pid, dmx_set_filter, dmx_set_pes_filter, dmx_start, dmx_stop,
+            dmx_add_pid, dmx_get_pes_pids, dmx_get_stc, dmx_remove_pid, dmx_set_buffer_size,
+            dmx_set_filter, dmx_set_pes_filter, dmx_start, dmx_stop,
         },
     },
     error::{DmxSetPesFilterError, DmxStartError},
@@ -15,24 +19,28 @@ use crate::{
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx-start.html#description))
 ///
 /// This ioctl call is used to start the actual filtering operation defined via the ioctl calls DMX_SET_FILTER or DMX_SET_PES_FILTER.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn start(fd: BorrowedFd) -> Result<(), DmxStartError> {
     // SAFETY: The argument is always a valid file descriptor. There should be no conditions or unhandled side-effects.
     unsafe { dmx_start(fd.as_raw_fd()) }.map_err(DmxStartError::from)?;
     Ok(())
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn stop(fd: BorrowedFd) -> Result<(), Errno> {
     // SAFETY: The argument is always a valid file descriptor. There should be no conditions or unhandled side-effects.
     unsafe { dmx_stop(fd.as_raw_fd()) }?;
     Ok(())
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn set_filter(fd: BorrowedFd, params: &DmxSctFilterParams) -> Result<(), Errno> {
     // SAFETY: The argument is always a valid file descriptor and C-compatible DmxSctFilterParams. There should be no conditions or unhandled side-effects.
     unsafe { dmx_set_filter(fd.as_raw_fd(), params) }?;
     Ok(())
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn set_pes_filter(
     fd: BorrowedFd,
     params: &DmxPesFilterParams,
@@ -42,10 +50,53 @@ pub fn set_pes_filter(
     Ok(())
 }
 
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx-set-buffer-size.html#description))
+///
+/// This ioctl call is used to set the size of the circular buffer used for filtered data.
+/// The default size is two maximum sized sections,
+/// i.e. if this function is not called a buffer size of 2 * 4096 bytes will be used.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn set_buffer_size(fd: BorrowedFd, size: u32) -> Result<(), Errno> {
+    let size = c_int::try_from(size).map_err(|_| Errno::EINVAL)?;
+    // SAFETY: FD is always valid, and the size is passed by value. There should be no conditions or unhandled side-effects.
+    unsafe { dmx_set_buffer_size(fd.as_raw_fd(), size) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx-get-stc.html#description))
+///
+/// This ioctl call returns the current value of the system time counter (which is driven by a PES filter of type DMX_PES_PCR).
+/// Some hardware supports more than one STC, so you must specify which one by setting the num field of stc before the ioctl (range 0…n).
+/// The result is returned in form of a ratio with a 64 bit numerator and a 32 bit denominator,
+/// so the real 90kHz STC value is stc->stc / stc->base.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn get_stc(fd: BorrowedFd, num: u32) -> Result<DmxStc, Errno> {
+    let mut stc = DmxStc {
+        num,
+        base: 0,
+        stc: 0,
+    };
+    // SAFETY: FD is always valid, DmxStc is C-compatible and the kernel only writes within it. There should be no conditions or unhandled side-effects.
+    unsafe { dmx_get_stc(fd.as_raw_fd(), &mut stc) }?;
+    Ok(stc)
+}
+
+/// Returns the PIDs of the audio, video, teletext, subtitle and PCR PES filters of the demux (the first set of [DmxTsPes](crate::demux::data::DmxTsPes)).
+///
+/// This ioctl is not documented upstream. A PID of 0xFFFF means no filter of that type is set.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn get_pes_pids(fd: BorrowedFd) -> Result<[u16; 5], Errno> {
+    let mut pids = [0; 5];
+    // SAFETY: FD is always valid, the array matches the size the kernel writes. There should be no conditions or unhandled side-effects.
+    unsafe { dmx_get_pes_pids(fd.as_raw_fd(), &mut pids) }?;
+    Ok(pids)
+}
+
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx-add-pid.html#description))
 ///
 /// This ioctl call allows to add multiple PIDs to a transport stream filter previously
 /// set up with DMX_SET_PES_FILTER and output equal to DMX_OUT_TSDEMUX_TAP.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn add_pid(fd: BorrowedFd, pid: u16) -> Result<(), Errno> {
     // SAFETY: FD is always valid, PID can be any u16. There should be no conditions or unhandled side-effects.
     unsafe { dmx_add_pid(fd.as_raw_fd(), &pid) }?;
@@ -57,6 +108,7 @@ pub fn add_pid(fd: BorrowedFd, pid: u16) -> Result<(), Errno> {
 /// This ioctl call allows to remove a PID when multiple PIDs are set on a transport stream filter,
 /// e. g. a filter previously set up with output equal to DMX_OUT_TSDEMUX_TAP,
 /// created via either DMX_SET_PES_FILTER or DMX_ADD_PID.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn remove_pid(fd: BorrowedFd, pid: u16) -> Result<(), Errno> {
     // SAFETY: FD is always valid, PID can be any u16. There should be no conditions or unhandled side-effects.
     unsafe { dmx_remove_pid(fd.as_raw_fd(), &pid) }?;
@@ -1,4 +1,7 @@
-use nix::{ioctl_none, ioctl_read, ioctl_readwrite, ioctl_write_ptr};
+use nix::{
+    ioctl_none, ioctl_read, ioctl_readwrite, ioctl_write_int_bad, ioctl_write_ptr,
+    request_code_none,
+};
 
 use crate::{
     IOCTL_TYPE,
@@ -28,8 +31,10 @@ ioctl_write_ptr!(
 );
 
 const DMX_SET_BUFFER_SIZE: u8 = 45;
-// TODO: dmx.h and documentation are inconsistent, header says there is no parameter while docs want an unsigned long for size
-ioctl_none!(dmx_set_buffer_size, IOCTL_TYPE, DMX_SET_BUFFER_SIZE);
+ioctl_write_int_bad!(
+    dmx_set_buffer_size,
+    request_code_none!(IOCTL_TYPE, DMX_SET_BUFFER_SIZE)
+); // dmx.h declares no parameter, but the size is passed by value
 
 const DMX_GET_PES_PIDS: u8 = 47;
 ioctl_read!(dmx_get_pes_pids, IOCTL_TYPE, DMX_GET_PES_PIDS, [u16; 5]);
@@ -1,3 +1,11 @@
 pub mod data;
+#[cfg(feature = "std")]
 pub mod functions;
+#[cfg(feature = "std")]
 pub mod ioctl;
+#[cfg(feature = "std")]
+pub mod psi;
+#[cfg(feature = "std")]
+pub mod section;
+#[cfg(feature = "std")]
+pub mod wrapper;
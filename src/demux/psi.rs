@@ -0,0 +1,162 @@
+//! Minimal parsing of the PAT and PMT, to find the PIDs of a service
+
+use std::collections::BTreeMap;
+
+use crate::{
+    demux::section::{SECTION_HEADER_SIZE, section_size},
+    error::PsiError,
+};
+
+/// PID carrying the Program Association Table.
+pub const PAT_PID: u16 = 0x0000;
+
+/// Table id of the Program Association Table.
+pub const PAT_TABLE_ID: u8 = 0x00;
+/// Table id of a Program Map Table.
+pub const PMT_TABLE_ID: u8 = 0x02;
+
+/// Common stream types found in a PMT, from ISO/IEC 13818-1.
+pub mod stream_type {
+    pub const MPEG1_VIDEO: u8 = 0x01;
+    pub const MPEG2_VIDEO: u8 = 0x02;
+    pub const MPEG1_AUDIO: u8 = 0x03;
+    pub const MPEG2_AUDIO: u8 = 0x04;
+    /// Private data in PES packets. In DVB, this carries AC-3, teletext and subtitles, told apart by descriptors.
+    pub const PRIVATE_PES: u8 = 0x06;
+    pub const AAC_AUDIO: u8 = 0x0F;
+    pub const H264_VIDEO: u8 = 0x1B;
+    pub const HEVC_VIDEO: u8 = 0x24;
+}
+
+/// Size of the long section header, up to and including the last section number.
+const LONG_HEADER_SIZE: usize = 8;
+
+/// Size of the CRC32 closing the section.
+const CRC_SIZE: usize = 4;
+
+/// Program Association Table: where to find the PMT of each program (service).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pat {
+    pub transport_stream_id: u16,
+    /// PMT PID of each program, by program number.
+    pub programs: BTreeMap<u16, u16>,
+    /// PID of the Network Information Table, announced as program 0.
+    pub network_pid: Option<u16>,
+}
+
+impl Pat {
+    /// Parses a PAT section. The CRC32 is not checked, see [SectionReader](crate::demux::section::SectionReader)
+    /// or [SectionFilter::check_crc](crate::demux::data::SectionFilter::check_crc) for that.
+    ///
+    /// Only holds the programs of this section. Large PATs may be split in several sections.
+    pub fn parse(section: &[u8]) -> Result<Pat, PsiError> {
+        let body = long_section_body(section, PAT_TABLE_ID)?;
+
+        let mut programs = BTreeMap::new();
+        let mut network_pid = None;
+        for entry in body.chunks_exact(4) {
+            let program_number = u16::from_be_bytes([entry[0], entry[1]]);
+            let pid = pid(entry[2], entry[3]);
+            if program_number == 0 {
+                network_pid = Some(pid);
+            } else {
+                programs.insert(program_number, pid);
+            }
+        }
+
+        Ok(Pat {
+            transport_stream_id: u16::from_be_bytes([section[3], section[4]]),
+            programs,
+            network_pid,
+        })
+    }
+}
+
+/// An elementary stream (video, audio, subtitles...) of a program.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ElementaryStream {
+    /// Kind of stream, see [stream_type].
+    pub stream_type: u8,
+    pub pid: u16,
+}
+
+/// Program Map Table: the elementary streams of one program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pmt {
+    pub program_number: u16,
+    /// PID carrying the clock reference of the program, often the video PID.
+    pub pcr_pid: u16,
+    /// Streams in the order of the table. Descriptors are skipped.
+    pub streams: Vec<ElementaryStream>,
+}
+
+impl Pmt {
+    /// Parses a PMT section. The CRC32 is not checked, see [Pat::parse].
+    pub fn parse(section: &[u8]) -> Result<Pmt, PsiError> {
+        let body = long_section_body(section, PMT_TABLE_ID)?;
+        if body.len() < 4 {
+            return Err(PsiError::Malformed);
+        }
+
+        let program_info_length = descriptors_length(body[2], body[3]);
+        let mut rest = body
+            .get(4 + program_info_length..)
+            .ok_or(PsiError::Malformed)?;
+        let mut streams = Vec::new();
+        while !rest.is_empty() {
+            let [stream_type, pid_high, pid_low, length_high, length_low, ..] = *rest else {
+                return Err(PsiError::Malformed);
+            };
+            streams.push(ElementaryStream {
+                stream_type,
+                pid: pid(pid_high, pid_low),
+            });
+            let es_info_length = descriptors_length(length_high, length_low);
+            rest = rest.get(5 + es_info_length..).ok_or(PsiError::Malformed)?;
+        }
+
+        Ok(Pmt {
+            program_number: u16::from_be_bytes([section[3], section[4]]),
+            pcr_pid: pid(body[0], body[1]),
+            streams,
+        })
+    }
+
+    /// PID of each stream type. When several streams share a type (several audio languages...), the first one is kept.
+    pub fn pids_by_stream_type(&self) -> BTreeMap<u8, u16> {
+        let mut pids = BTreeMap::new();
+        for stream in &self.streams {
+            pids.entry(stream.stream_type).or_insert(stream.pid);
+        }
+        pids
+    }
+}
+
+/// Checks the header of a long section and returns what lies between the header and the CRC32.
+fn long_section_body(section: &[u8], table_id: u8) -> Result<&[u8], PsiError> {
+    if section.len() < SECTION_HEADER_SIZE {
+        return Err(PsiError::Malformed);
+    }
+    if section[0] != table_id {
+        return Err(PsiError::UnexpectedTable {
+            expected: table_id,
+            found: section[0],
+        });
+    }
+
+    let size = section_size(&[section[0], section[1], section[2]]);
+    if size < LONG_HEADER_SIZE + CRC_SIZE || section.len() < size {
+        return Err(PsiError::Malformed);
+    }
+    Ok(&section[LONG_HEADER_SIZE..size - CRC_SIZE])
+}
+
+/// PIDs are the 13 low bits of two bytes.
+fn pid(high: u8, low: u8) -> u16 {
+    u16::from_be_bytes([high & 0x1F, low])
+}
+
+/// Descriptor loop lengths are the 12 low bits of two bytes.
+fn descriptors_length(high: u8, low: u8) -> usize {
+    usize::from(u16::from_be_bytes([high & 0x0F, low]))
+}
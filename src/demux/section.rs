@@ -0,0 +1,139 @@
+//! Reassembly of PSI sections (PAT, PMT...) out of bytes read in arbitrary chunks
+
+use std::collections::VecDeque;
+
+use crate::{
+    demux::wrapper::Demux,
+    error::{DmxReadError, SectionError},
+};
+
+/// Largest possible section, header included.
+pub(crate) const MAX_SECTION_SIZE: usize = 4096;
+
+/// Size of the section header, up to and including the section length field.
+pub(crate) const SECTION_HEADER_SIZE: usize = 3;
+
+/// Size of the CRC32 closing sections that use the long syntax.
+const CRC_SIZE: usize = 4;
+
+/// Table id of stuffing bytes, after the last section of a packet.
+const STUFFING_TABLE_ID: u8 = 0xFF;
+
+/// Total size of the section starting with `header`, from its section length field.
+pub(crate) fn section_size(header: &[u8; SECTION_HEADER_SIZE]) -> usize {
+    SECTION_HEADER_SIZE + ((usize::from(header[1] & 0x0F) << 8) | usize::from(header[2]))
+}
+
+/// CRC32 used by MPEG-2 sections: polynomial 0x04C11DB7, initial value 0xFFFFFFFF, not reflected, no final xor.
+///
+/// Computed over a whole section including its CRC32 field, the result is 0 if the section is intact.
+pub fn crc32_mpeg2(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte) << 24;
+        for _ in 0..8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04C1_1DB7
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Accumulates bytes and yields complete sections, for when sections are not read one at a time.
+///
+/// Bytes are added with [SectionReader::push] or [SectionReader::fill], and complete sections
+/// are taken out with [SectionReader::next_section]. Sections using the long syntax
+/// (section syntax indicator set, as in PAT and PMT) have their CRC32 checked.
+///
+/// For a demux with a section filter, [Demux::read_section] already returns whole sections.
+#[derive(Debug, Default)]
+pub struct SectionReader {
+    buf: VecDeque<u8>,
+}
+
+impl SectionReader {
+    pub fn new() -> SectionReader {
+        SectionReader::default()
+    }
+
+    /// Adds bytes that follow the ones pushed before.
+    pub fn push(&mut self, data: &[u8]) {
+        self.buf.extend(data);
+    }
+
+    /// Reads once from the demux and adds what was read. Returns the number of bytes read.
+    pub fn fill(&mut self, demux: &Demux) -> Result<usize, DmxReadError> {
+        let mut chunk = [0; MAX_SECTION_SIZE];
+        let len = demux.read_pes(&mut chunk)?;
+        self.push(&chunk[..len]);
+        Ok(len)
+    }
+
+    /// Number of bytes received but not yet part of a returned section.
+    pub fn pending(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Forgets the bytes received so far, for example after a [DmxReadError::Overflow].
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    /// Takes the next complete section out, or returns [None] if more bytes are needed.
+    ///
+    /// A section with a wrong CRC32 is dropped and reported as an error, the following ones can still be read.
+    /// Stuffing bytes are skipped.
+    pub fn next_section(&mut self) -> Option<Result<Vec<u8>, SectionError>> {
+        loop {
+            if self.buf.front() == Some(&STUFFING_TABLE_ID) {
+                let stuffing = self
+                    .buf
+                    .iter()
+                    .take_while(|&&b| b == STUFFING_TABLE_ID)
+                    .count();
+                self.buf.drain(..stuffing);
+                continue;
+            }
+            if self.buf.len() < SECTION_HEADER_SIZE {
+                return None;
+            }
+
+            let header = [self.buf[0], self.buf[1], self.buf[2]];
+            let size = section_size(&header);
+            if size > MAX_SECTION_SIZE {
+                // Lost track of the section boundaries, nothing after this can be trusted
+                self.buf.clear();
+                return Some(Err(SectionError::TooLong(size)));
+            }
+            if self.buf.len() < size {
+                return None;
+            }
+
+            let section: Vec<u8> = self.buf.drain(..size).collect();
+            let long_syntax = header[1] & 0x80 != 0;
+            if long_syntax {
+                if size < SECTION_HEADER_SIZE + CRC_SIZE {
+                    return Some(Err(SectionError::TooShort(size)));
+                }
+                if crc32_mpeg2(&section) != 0 {
+                    return Some(Err(SectionError::CrcMismatch {
+                        table_id: header[0],
+                    }));
+                }
+            }
+            return Some(Ok(section));
+        }
+    }
+}
+
+impl Iterator for SectionReader {
+    type Item = Result<Vec<u8>, SectionError>;
+
+    /// Same as [SectionReader::next_section]: ends when more bytes are needed, and can be resumed after pushing them.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_section()
+    }
+}
@@ -0,0 +1,305 @@
+//! Owned handle on a demux device, on top of the raw functions
+
+use std::{
+    collections::BTreeMap,
+    fs::OpenOptions,
+    io,
+    os::{
+        fd::{AsFd, BorrowedFd, OwnedFd},
+        unix::fs::OpenOptionsExt as _,
+    },
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use nix::{errno::Errno, unistd::read};
+
+use crate::{
+    demux::{
+        data::{
+            DMX_IMMEDIATE_START, DMX_PID_ALL, DmxInput, DmxOutput, DmxPesFilterParams,
+            DmxSctFilterParams, DmxStc, DmxTsPes, SectionFilter,
+        },
+        functions::{
+            add_pid, get_pes_pids, get_stc, remove_pid, set_buffer_size, set_filter,
+            set_pes_filter, start, stop,
+        },
+        psi::{PAT_PID, PAT_TABLE_ID, PMT_TABLE_ID, Pat, Pmt},
+        section::{MAX_SECTION_SIZE, SECTION_HEADER_SIZE, section_size},
+    },
+    error::{DmxReadError, DmxSetPesFilterError, DmxStartError, PsiError},
+    fd::{nonblocking_flag, set_nonblocking, wait_readable},
+};
+
+/// Upper bound when probing STCs, in case a driver never reports the end of the list.
+const MAX_STCS: u32 = 16;
+
+/// An opened demux device, such as `/dev/dvb/adapter0/demux0`.
+#[derive(Debug)]
+pub struct Demux {
+    fd: OwnedFd,
+}
+
+impl Demux {
+    /// Opens the demux at given path for reading and writing, as required for setting filters.
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open(path: impl AsRef<Path>, nonblocking: bool) -> io::Result<Demux> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(nonblocking_flag(nonblocking))
+            .open(path)?;
+        Ok(Demux { fd: file.into() })
+    }
+
+    /// Switches the demux between blocking and non-blocking (`O_NONBLOCK`) mode after it was opened.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        set_nonblocking(self.fd.as_fd(), nonblocking)
+    }
+
+    /// Starts filtering, see [start].
+    pub fn start(&self) -> Result<(), DmxStartError> {
+        start(self.fd.as_fd())
+    }
+
+    /// Stops filtering, see [stop].
+    pub fn stop(&self) -> Result<(), Errno> {
+        stop(self.fd.as_fd())
+    }
+
+    /// Sets the size of the kernel buffer holding filtered data, see [set_buffer_size].
+    ///
+    /// Increase it when reads keep failing with [DmxReadError::Overflow].
+    pub fn set_buffer_size(&self, size: u32) -> Result<(), Errno> {
+        set_buffer_size(self.fd.as_fd(), size)
+    }
+
+    /// Sets a section filter, see [set_filter].
+    pub fn set_filter(&self, params: &DmxSctFilterParams) -> Result<(), Errno> {
+        set_filter(self.fd.as_fd(), params)
+    }
+
+    /// Sets a PES filter, see [set_pes_filter].
+    pub fn set_pes_filter(&self, params: &DmxPesFilterParams) -> Result<(), DmxSetPesFilterError> {
+        set_pes_filter(self.fd.as_fd(), params)
+    }
+
+    /// Adds a PID to the current transport stream filter, see [add_pid].
+    pub fn add_pid(&self, pid: u16) -> Result<(), Errno> {
+        add_pid(self.fd.as_fd(), pid)
+    }
+
+    /// Removes a PID from the current transport stream filter, see [remove_pid].
+    pub fn remove_pid(&self, pid: u16) -> Result<(), Errno> {
+        remove_pid(self.fd.as_fd(), pid)
+    }
+
+    /// Filters several PIDs at once, multiplexed in a single transport stream, and starts filtering.
+    ///
+    /// A single [DMX_OUT_TSDEMUX_TAP](DmxOutput::DMX_OUT_TSDEMUX_TAP) filter is set on the first PID, then the others are added with [add_pid].
+    /// The 188-byte packets of all PIDs are then read from this demux, for example with [Demux::read_pes].
+    /// Fails with [DmxSetPesFilterError::NoPids] if `pids` is empty. If a PID cannot be added, the filter is stopped.
+    pub fn set_pid_filter_ts(&self, pids: &[u16]) -> Result<(), DmxSetPesFilterError> {
+        let (&first, others) = pids.split_first().ok_or(DmxSetPesFilterError::NoPids)?;
+
+        self.set_pes_filter(&DmxPesFilterParams {
+            pid: first,
+            input: DmxInput::DMX_IN_FRONTEND,
+            output: DmxOutput::DMX_OUT_TSDEMUX_TAP,
+            pes_type: DmxTsPes::DMX_PES_OTHER,
+            flags: DMX_IMMEDIATE_START,
+        })?;
+        for &pid in others {
+            if let Err(e) = self.add_pid(pid) {
+                // Do not leave the filter running on part of the PIDs, the error to report is the one of add_pid
+                let _ = self.stop();
+                return Err(e.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends the entire transport stream to the DVR device, using the special [DMX_PID_ALL] PID, and starts filtering.
+    ///
+    /// Useful to record a whole mux for later offline processing.
+    pub fn set_pid_filter_all(&self) -> Result<(), DmxSetPesFilterError> {
+        self.set_pes_filter(&DmxPesFilterParams {
+            pid: DMX_PID_ALL,
+            input: DmxInput::DMX_IN_FRONTEND,
+            output: DmxOutput::DMX_OUT_TS_TAP,
+            pes_type: DmxTsPes::DMX_PES_OTHER,
+            flags: DMX_IMMEDIATE_START,
+        })
+    }
+
+    /// Reads the System Time Counter number `num`, see [get_stc].
+    pub fn get_stc(&self, num: u32) -> Result<DmxStc, Errno> {
+        get_stc(self.fd.as_fd(), num)
+    }
+
+    /// PIDs of the audio, video, teletext, subtitle and PCR PES filters, see [get_pes_pids].
+    pub fn get_pes_pids(&self) -> Result<[u16; 5], Errno> {
+        get_pes_pids(self.fd.as_fd())
+    }
+
+    /// Reads every System Time Counter of the hardware, probing numbers from 0 upward until `EINVAL`.
+    ///
+    /// Most hardware has a single STC, but some have several.
+    pub fn all_stcs(&self) -> Result<Vec<DmxStc>, Errno> {
+        let mut stcs = Vec::new();
+        for num in 0..MAX_STCS {
+            match self.get_stc(num) {
+                Ok(stc) => stcs.push(stc),
+                Err(Errno::EINVAL) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(stcs)
+    }
+
+    /// Reads data from a PES filter into `buf`, returning how many bytes were read.
+    ///
+    /// What is read depends on the output type of the filter:
+    /// - [DMX_OUT_TAP](crate::demux::data::DmxOutput::DMX_OUT_TAP) yields the PES packets, without the transport stream headers
+    /// - [DMX_OUT_TSDEMUX_TAP](crate::demux::data::DmxOutput::DMX_OUT_TSDEMUX_TAP) yields whole 188-byte transport stream packets
+    /// - with [DMX_OUT_TS_TAP](crate::demux::data::DmxOutput::DMX_OUT_TS_TAP), the 188-byte packets go to the DVR device instead, and nothing can be read here
+    ///
+    /// Unlike sections, data is a continuous stream: a read may end in the middle of a packet.
+    /// Sections read this way can be put back together with a [SectionReader](crate::demux::section::SectionReader).
+    /// Fails with [DmxReadError::Overflow] if data was lost because it was not read fast enough.
+    pub fn read_pes(&self, buf: &mut [u8]) -> Result<usize, DmxReadError> {
+        Ok(read(&self.fd, buf)?)
+    }
+
+    /// Reads one complete section, after a section filter was set and started.
+    ///
+    /// Each read on a section filter returns at most one section. Fails with [DmxReadError::Timeout] if no section came within `timeout`.
+    ///
+    /// If the kernel buffer overflowed because sections were not read fast enough, [DmxReadError::Overflow] is returned once and sections were lost.
+    /// The filter keeps running, so the next call reads the following sections normally.
+    pub fn read_section(&self, timeout: Duration) -> Result<Vec<u8>, DmxReadError> {
+        if !wait_readable(self.fd.as_fd(), timeout)? {
+            return Err(DmxReadError::Timeout);
+        }
+
+        let mut section = vec![0; MAX_SECTION_SIZE];
+        let mut len = read(&self.fd, &mut section)?;
+
+        // The section length field tells how much is left, keep reading if the kernel handed it over in several parts
+        if len >= SECTION_HEADER_SIZE {
+            let declared =
+                section_size(&[section[0], section[1], section[2]]).min(MAX_SECTION_SIZE);
+            while len < declared {
+                match read(&self.fd, &mut section[len..declared])? {
+                    0 => break,
+                    read_len => len += read_len,
+                }
+            }
+        }
+
+        section.truncate(len);
+        Ok(section)
+    }
+
+    /// Sets a section filter, reads a single section and stops, replacing the filter of this demux.
+    ///
+    /// The filter is set up with [DMX_ONESHOT](crate::demux::data::DMX_ONESHOT), [DMX_IMMEDIATE_START] and
+    /// [DMX_CHECK_CRC](crate::demux::data::DMX_CHECK_CRC) on top of its own flags,
+    /// so it never keeps running. Fails with [DmxReadError::Timeout] if no section came within `timeout`.
+    pub fn read_one_section(
+        &self,
+        filter: SectionFilter,
+        timeout: Duration,
+    ) -> Result<Vec<u8>, DmxReadError> {
+        let params = filter.oneshot().immediate_start().check_crc().build();
+        self.set_filter(&params).map_err(DmxReadError::SetFilter)?;
+
+        let section = self.read_section(timeout);
+        // The kernel only stops oneshot filters once a section came
+        let stopped = self.stop();
+        let section = section?;
+        stopped.map_err(DmxReadError::Undefined)?;
+        Ok(section)
+    }
+
+    /// Reads the PAT of the transport stream the frontend is tuned to, replacing the filter of this demux.
+    ///
+    /// Only the first section is read, which holds every program unless the PAT is unusually large.
+    pub fn read_pat(&self, timeout: Duration) -> Result<Pat, PsiError> {
+        let filter = SectionFilter::new().pid(PAT_PID).table_id(PAT_TABLE_ID);
+        let section = self.read_one_section(filter, timeout)?;
+        Pat::parse(&section)
+    }
+
+    /// Reads the PAT, then the PMT of a program (service), replacing the filter of this demux.
+    ///
+    /// `timeout` applies to each table. Some PMTs are shared by several programs, sections of other programs are skipped.
+    pub fn read_pmt(&self, program_number: u16, timeout: Duration) -> Result<Pmt, PsiError> {
+        let pat = self.read_pat(timeout)?;
+        let pmt_pid = *pat
+            .programs
+            .get(&program_number)
+            .ok_or(PsiError::ProgramNotFound(program_number))?;
+
+        self.set_table_filter(pmt_pid, PMT_TABLE_ID)?;
+        let pmt = self.find_pmt(program_number, timeout);
+        // The filter is not oneshot, it keeps running whether a PMT was found or not
+        let stopped = self.stop();
+        let pmt = pmt?;
+        stopped.map_err(PsiError::Filter)?;
+        Ok(pmt)
+    }
+
+    /// PID of each stream type of a program, such as [H264_VIDEO](crate::demux::psi::stream_type::H264_VIDEO),
+    /// found through the PAT and PMT. See [Demux::read_pmt] and [Pmt::pids_by_stream_type].
+    pub fn stream_pids(
+        &self,
+        program_number: u16,
+        timeout: Duration,
+    ) -> Result<BTreeMap<u8, u16>, PsiError> {
+        Ok(self
+            .read_pmt(program_number, timeout)?
+            .pids_by_stream_type())
+    }
+
+    /// Reads PMT sections from the running filter until one describes the program.
+    fn find_pmt(&self, program_number: u16, timeout: Duration) -> Result<Pmt, PsiError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let pmt = Pmt::parse(&self.read_section(remaining)?)?;
+            if pmt.program_number == program_number {
+                return Ok(pmt);
+            }
+        }
+    }
+
+    fn set_table_filter(&self, pid: u16, table_id: u8) -> Result<(), PsiError> {
+        let filter = SectionFilter::new()
+            .pid(pid)
+            .table_id(table_id)
+            .check_crc()
+            .immediate_start()
+            .build();
+        self.set_filter(&filter).map_err(PsiError::Filter)
+    }
+}
+
+impl AsFd for Demux {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<OwnedFd> for Demux {
+    fn from(fd: OwnedFd) -> Self {
+        Demux { fd }
+    }
+}
+
+impl From<Demux> for OwnedFd {
+    fn from(demux: Demux) -> Self {
+        demux.fd
+    }
+}
@@ -2,10 +2,20 @@
 
 use std::{
     collections::HashMap,
+    fmt,
     fs::{read_dir, read_to_string},
-    path::PathBuf,
+    io,
+    path::{Path, PathBuf},
 };
 
+use crate::{
+    ca::wrapper::Ca, demux::wrapper::Demux, dvr::wrapper::Dvr, error::DevicesError,
+    frontend::wrapper::Frontend,
+};
+
+/// Where the kernel lists DVB sub-devices.
+const SYSFS_DVB: &str = "/sys/class/dvb";
+
 /// A DVB adapter currently attached to the system.
 #[derive(Debug)]
 pub struct Adapter {
@@ -19,9 +29,17 @@ pub struct Adapter {
     demux_count: usize,
     dvr_count: usize,
     net_count: usize,
+    ca_count: usize,
 }
 
 impl Adapter {
+    /// Returns the number of this adapter, as in `/dev/dvb/adapterN`.
+    ///
+    /// Numbers are given in the order devices are detected, they may change across reboots.
+    pub fn adapter_id(&self) -> &str {
+        &self.adapter_id
+    }
+
     /// Returns the manufacturer string of the device
     pub fn manufacturer(&self) -> &str {
         &self.manufacturer
@@ -47,22 +65,66 @@ impl Adapter {
         &self.serial
     }
 
+    /// Returns the number of frontends of this adapter, one per tuner.
+    pub fn frontend_count(&self) -> usize {
+        self.frontend_count
+    }
+
+    /// Returns the number of demuxes of this adapter.
+    pub fn demux_count(&self) -> usize {
+        self.demux_count
+    }
+
+    /// Returns the number of DVRs of this adapter.
+    pub fn dvr_count(&self) -> usize {
+        self.dvr_count
+    }
+
+    /// Returns the number of network interfaces of this adapter.
+    pub fn net_count(&self) -> usize {
+        self.net_count
+    }
+
+    /// Returns the number of Conditional Access devices of this adapter.
+    pub fn ca_count(&self) -> usize {
+        self.ca_count
+    }
+
     /// Returns a path to the first frontend of this adapter.
-    pub fn get_first_frontend(&self) -> PathBuf {
-        if self.frontend_count < 1 {
-            panic!("dvb adapter does not have even 1 frontend. How is this possible ?")
+    pub fn get_first_frontend(&self) -> Option<PathBuf> {
+        self.get_frontend(0)
+    }
+
+    /// Returns a path to the frontend with given index, if this adapter has it.
+    ///
+    /// Cards with multiple tuners expose one frontend per tuner (frontend0, frontend1...).
+    pub fn get_frontend(&self, index: usize) -> Option<PathBuf> {
+        if index >= self.frontend_count {
+            return None;
         }
 
-        format_dev_adapter(&self.adapter_id).join("frontend0")
+        Some(format_dev_adapter(&self.adapter_id).join(format!("frontend{}", index)))
+    }
+
+    /// Returns paths to all frontends of this adapter.
+    pub fn frontend_paths(&self) -> Vec<PathBuf> {
+        (0..self.frontend_count)
+            .filter_map(|i| self.get_frontend(i))
+            .collect()
     }
 
     /// Returns a path to the first demux of this adapter.
-    pub fn get_first_demux(&self) -> PathBuf {
-        if self.demux_count < 1 {
-            panic!()
+    pub fn get_first_demux(&self) -> Option<PathBuf> {
+        self.get_demux(0)
+    }
+
+    /// Returns a path to the demux with given index, if this adapter has it.
+    pub fn get_demux(&self, index: usize) -> Option<PathBuf> {
+        if index >= self.demux_count {
+            return None;
         }
 
-        format_dev_adapter(&self.adapter_id).join("demux0")
+        Some(format_dev_adapter(&self.adapter_id).join(format!("demux{}", index)))
     }
 
     pub fn get_first_dvr(&self) -> Option<PathBuf> {
@@ -80,6 +142,74 @@ impl Adapter {
 
         Some(format_dev_adapter(&self.adapter_id).join("net0"))
     }
+
+    /// Returns a path to the first Conditional Access device of this adapter, used to talk to a CI module.
+    pub fn get_first_ca(&self) -> Option<PathBuf> {
+        if self.ca_count < 1 {
+            return None;
+        }
+
+        Some(format_dev_adapter(&self.adapter_id).join("ca0"))
+    }
+
+    /// Opens the frontend with given index for reading and writing.
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open_frontend(&self, index: usize, nonblocking: bool) -> io::Result<Frontend> {
+        let path = self
+            .get_frontend(index)
+            .ok_or_else(|| missing_device("frontend", index))?;
+        Frontend::open(path, nonblocking)
+    }
+
+    /// Opens the demux with given index for reading and writing.
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open_demux(&self, index: usize, nonblocking: bool) -> io::Result<Demux> {
+        let path = self
+            .get_demux(index)
+            .ok_or_else(|| missing_device("demux", index))?;
+        Demux::open(path, nonblocking)
+    }
+
+    /// Opens the first DVR of this adapter for reading the transport stream.
+    pub fn open_dvr(&self) -> io::Result<Dvr> {
+        let path = self
+            .get_first_dvr()
+            .ok_or_else(|| missing_device("dvr", 0))?;
+        Dvr::open(path, false)
+    }
+
+    /// Opens the first Conditional Access device of this adapter for reading and writing.
+    pub fn open_ca(&self) -> io::Result<Ca> {
+        let path = self.get_first_ca().ok_or_else(|| missing_device("ca", 0))?;
+        Ca::open(path, false)
+    }
+}
+
+/// Renders as `adapter0 (Hauppauge WinTV, serial ABC123)`, leaving out attributes the device does not expose.
+impl fmt::Display for Adapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "adapter{}", self.adapter_id)?;
+
+        let serial = (!self.serial.is_empty()).then(|| format!("serial {}", self.serial));
+        let details: Vec<&str> = [Some(self.product.as_str()), serial.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|detail| !detail.is_empty())
+            .collect();
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+fn missing_device(kind: &str, index: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("dvb adapter does not have {}{}", kind, index),
+    )
 }
 
 fn format_dev_adapter(adapter_id: &str) -> PathBuf {
@@ -89,88 +219,201 @@ fn format_dev_adapter(adapter_id: &str) -> PathBuf {
         .join(format!("adapter{}", adapter_id))
 }
 
+/// Opens `/dev/dvb/adapterN/frontendM` for reading and writing, without looking at sysfs.
+///
+/// For when the numbers are already known, such as `open_frontend(0, 0)`. See [Adapter::open_frontend] otherwise.
+pub fn open_frontend(adapter: u32, frontend: u32) -> io::Result<Frontend> {
+    Frontend::open(
+        format_dev_adapter(&adapter.to_string()).join(format!("frontend{}", frontend)),
+        false,
+    )
+}
+
+/// Opens `/dev/dvb/adapterN/demuxM` for reading and writing, without looking at sysfs.
+pub fn open_demux(adapter: u32, demux: u32) -> io::Result<Demux> {
+    Demux::open(
+        format_dev_adapter(&adapter.to_string()).join(format!("demux{}", demux)),
+        false,
+    )
+}
+
+/// Opens `/dev/dvb/adapterN/dvrM` for reading the transport stream, without looking at sysfs.
+pub fn open_dvr(adapter: u32, dvr: u32) -> io::Result<Dvr> {
+    Dvr::open(
+        format_dev_adapter(&adapter.to_string()).join(format!("dvr{}", dvr)),
+        false,
+    )
+}
+
+/// Reads a sysfs attribute of a device, returning an empty string if it is not exposed.
+///
+/// Many USB tuners do not provide every attribute (serial, manufacturer...), this should not prevent listing them.
+fn read_attribute(device_dir: &Path, name: &str) -> String {
+    read_to_string(device_dir.join(name))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default()
+}
+
 /// List all DVB adapters recognized by the system.
-pub fn list_all_adapters() -> Vec<Adapter> {
-    // TODO: Terrible code but oh well it seems to work. Could use /dev/dvb/ instead
+pub fn list_all_adapters() -> Result<Vec<Adapter>, DevicesError> {
+    list_adapters_from(Path::new(SYSFS_DVB))
+}
+
+/// List all DVB adapters found in a directory laid out like `/sys/class/dvb`.
+///
+/// Useful when sysfs is mounted elsewhere, as in some containers, or to test against a fixture directory.
+/// Device paths such as [Adapter::get_first_frontend] still point into `/dev/dvb`.
+pub fn list_adapters_from(base: &Path) -> Result<Vec<Adapter>, DevicesError> {
+    adapters_from(base).collect()
+}
 
-    let base_path = PathBuf::from("/sys/class/dvb");
+/// Iterate over DVB adapters recognized by the system.
+///
+/// Sysfs attributes of each adapter are only read when it is yielded,
+/// so stopping early (e.g. when looking for a specific serial) avoids needless reads.
+pub fn adapters() -> impl Iterator<Item = Result<Adapter, DevicesError>> {
+    adapters_from(Path::new(SYSFS_DVB))
+}
+
+fn adapters_from(base: &Path) -> impl Iterator<Item = Result<Adapter, DevicesError>> {
+    let (groups, error) = match group_sub_devices(base) {
+        Ok(groups) => (groups, None),
+        Err(e) => (HashMap::new(), Some(e)),
+    };
+
+    error.map(Err).into_iter().chain(
+        groups
+            .into_iter()
+            .map(|(key, value)| Ok(read_adapter(&key, &value))),
+    )
+}
+
+/// Find the adapter with given serial number.
+///
+/// Adapter numbers may change across reboots, the serial is a stable way to find the same device again.
+/// Adapters that could not be read are skipped. Many devices have no serial, so an empty serial never matches.
+pub fn find_adapter_by_serial(serial: &str) -> Option<Adapter> {
+    if serial.is_empty() {
+        return None;
+    }
+    adapters()
+        .filter_map(Result::ok)
+        .find(|adapter| adapter.serial == serial)
+}
+
+/// Find all adapters with given USB Vendor ID and Product ID (e.g. "2040" and "0265").
+///
+/// Adapters that could not be read are skipped.
+pub fn find_adapters_by_usb_id(vendor: &str, product: &str) -> Vec<Adapter> {
+    adapters()
+        .filter_map(Result::ok)
+        .filter(|adapter| adapter.id_vendor == vendor && adapter.id_product == product)
+        .collect()
+}
+
+/// Numbers of all adapters currently in sysfs. Empty if sysfs could not be read.
+#[cfg(feature = "hotplug")]
+pub(crate) fn adapter_ids() -> Vec<String> {
+    group_sub_devices(Path::new(SYSFS_DVB))
+        .map(|groups| {
+            groups
+                .keys()
+                .map(|key| adapter_id(key).to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reads the adapter with given number from sysfs, if it is there.
+#[cfg(feature = "hotplug")]
+pub(crate) fn adapter_by_id(id: &str) -> Option<Adapter> {
+    group_sub_devices(Path::new(SYSFS_DVB))
+        .ok()?
+        .into_iter()
+        .find(|(key, _)| adapter_id(key) == id)
+        .map(|(key, value)| read_adapter(&key, &value))
+}
+
+/// Keeps only the number part of an adapter key, such as `/sys/class/dvb/dvb0`.
+fn adapter_id(key: &str) -> &str {
+    let name = key.rsplit('/').next().unwrap_or(key);
+    name.strip_prefix("dvb").unwrap_or(name)
+}
+
+/// Groups all sub-devices (frontend, demux...) found in sysfs by the adapter they belong to.
+fn group_sub_devices(
+    base_path: &Path,
+) -> Result<HashMap<String, Vec<(String, String)>>, DevicesError> {
+    // TODO: Terrible code but oh well it seems to work. Could use /dev/dvb/ instead
 
     let mut adapters: HashMap<String, Vec<(String, String)>> = HashMap::new();
-    for entry in read_dir(base_path).unwrap() {
-        let entry = entry.unwrap();
+    for entry in read_dir(base_path).map_err(DevicesError::ReadDir)? {
+        let entry = entry.map_err(DevicesError::ReadDir)?;
         let path = entry.path();
 
         if !path.is_dir() {
             continue;
         }
 
-        let thing = path.to_str().unwrap();
-        let (device, elm) = thing.split_once('.').unwrap();
+        let thing = path
+            .to_str()
+            .ok_or_else(|| DevicesError::UnexpectedName(path.clone()))?;
+        let (device, elm) = thing
+            .rsplit_once('.')
+            .ok_or_else(|| DevicesError::UnexpectedName(path.clone()))?;
         adapters
             .entry(device.to_string())
             .or_default()
             .push((thing.to_string(), elm.to_string()));
     }
 
-    let mut better: Vec<Adapter> = Vec::new();
-    for (key, value) in adapters.iter() {
-        let path = PathBuf::from(value[0].0.clone());
-
-        let device_dir = path.join("device");
-
-        // Read info about adapter
-        let manufacturer = read_to_string(device_dir.join("manufacturer"))
-            .unwrap()
-            .trim()
-            .to_string();
-        let product = read_to_string(device_dir.join("product"))
-            .unwrap()
-            .trim()
-            .to_string();
-        let id_vendor = read_to_string(device_dir.join("idVendor"))
-            .unwrap()
-            .trim()
-            .to_string();
-        let id_product = read_to_string(device_dir.join("idProduct"))
-            .unwrap()
-            .trim()
-            .to_string();
-        let serial = read_to_string(device_dir.join("serial"))
-            .unwrap()
-            .trim()
-            .to_string();
-
-        // Count sub-devices
-        let mut frontend_count = 0;
-        let mut demux_count = 0;
-        let mut dvr_count = 0;
-        let mut net_count = 0;
-        for (_, d) in value {
-            if d.starts_with("frontend") {
-                frontend_count += 1
-            } else if d.starts_with("demux") {
-                demux_count += 1
-            } else if d.starts_with("dvr") {
-                dvr_count += 1
-            } else if d.starts_with("net") {
-                net_count += 1
-            }
+    Ok(adapters)
+}
+
+/// Reads info about an adapter from its group of sub-devices.
+fn read_adapter(key: &str, value: &[(String, String)]) -> Adapter {
+    let path = PathBuf::from(value[0].0.clone());
+
+    let device_dir = path.join("device");
+
+    // Read info about adapter
+    let manufacturer = read_attribute(&device_dir, "manufacturer");
+    let product = read_attribute(&device_dir, "product");
+    let id_vendor = read_attribute(&device_dir, "idVendor");
+    let id_product = read_attribute(&device_dir, "idProduct");
+    let serial = read_attribute(&device_dir, "serial");
+
+    // Count sub-devices
+    let mut frontend_count = 0;
+    let mut demux_count = 0;
+    let mut dvr_count = 0;
+    let mut net_count = 0;
+    let mut ca_count = 0;
+    for (_, d) in value {
+        if d.starts_with("frontend") {
+            frontend_count += 1
+        } else if d.starts_with("demux") {
+            demux_count += 1
+        } else if d.starts_with("dvr") {
+            dvr_count += 1
+        } else if d.starts_with("net") {
+            net_count += 1
+        } else if d.starts_with("ca") {
+            ca_count += 1
         }
+    }
 
-        better.push(Adapter {
-            // Keep only the number part
-            adapter_id: key["/sys/class/dvb/dvb".len()..].to_string(),
-            manufacturer,
-            product,
-            id_vendor,
-            id_product,
-            serial,
-            frontend_count,
-            demux_count,
-            dvr_count,
-            net_count,
-        });
-    }
-
-    better
+    Adapter {
+        adapter_id: adapter_id(key).to_string(),
+        manufacturer,
+        product,
+        id_vendor,
+        id_product,
+        serial,
+        frontend_count,
+        demux_count,
+        dvr_count,
+        net_count,
+        ca_count,
+    }
 }
@@ -0,0 +1,88 @@
+//! Asynchronous reading of the DVR device with tokio
+
+use std::{
+    fs::OpenOptions,
+    io,
+    os::{
+        fd::{AsFd, BorrowedFd, OwnedFd},
+        unix::fs::OpenOptionsExt as _,
+    },
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use nix::{
+    fcntl::{FcntlArg, OFlag, fcntl},
+    unistd::read,
+};
+use tokio::io::{AsyncRead, ReadBuf, unix::AsyncFd};
+
+/// A DVR device registered with the tokio reactor, for reading the transport stream in an async task.
+///
+/// The device is always used in non-blocking mode. When no data is available, reads are pending instead of failing with `EAGAIN`.
+#[derive(Debug)]
+pub struct AsyncDvr {
+    inner: AsyncFd<OwnedFd>,
+}
+
+impl AsyncDvr {
+    /// Opens the DVR at given path read-only and non-blocking.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<AsyncDvr> {
+        let file = OpenOptions::new()
+            .read(true)
+            .custom_flags(OFlag::O_NONBLOCK.bits())
+            .open(path)?;
+        AsyncDvr::new(file.into())
+    }
+
+    /// Wraps an already opened DVR, enabling `O_NONBLOCK` on it if it wasn't set.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new(fd: OwnedFd) -> io::Result<AsyncDvr> {
+        let flags = OFlag::from_bits_retain(fcntl(&fd, FcntlArg::F_GETFL)?);
+        if !flags.contains(OFlag::O_NONBLOCK) {
+            fcntl(&fd, FcntlArg::F_SETFL(flags | OFlag::O_NONBLOCK))?;
+        }
+
+        Ok(AsyncDvr {
+            inner: AsyncFd::new(fd)?,
+        })
+    }
+
+    /// Unregisters the DVR from the reactor and gives back the file descriptor.
+    pub fn into_inner(self) -> OwnedFd {
+        self.inner.into_inner()
+    }
+}
+
+impl AsyncRead for AsyncDvr {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = ready!(self.inner.poll_read_ready(cx))?;
+
+            let unfilled = buf.initialize_unfilled();
+            // EAGAIN is turned into io::ErrorKind::WouldBlock, which makes try_io clear readiness so that we wait again
+            match guard.try_io(|inner| read(inner.get_ref(), unfilled).map_err(io::Error::from)) {
+                Ok(Ok(len)) => {
+                    buf.advance(len);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(err)) => return Poll::Ready(Err(err)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+impl AsFd for AsyncDvr {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.inner.get_ref().as_fd()
+    }
+}
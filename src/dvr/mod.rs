@@ -0,0 +1,3 @@
+#[cfg(feature = "tokio")]
+pub mod async_dvr;
+pub mod wrapper;
@@ -0,0 +1,164 @@
+//! Owned handle on a DVR device, reading the transport stream as packets
+
+use std::{
+    fs::OpenOptions,
+    io::{self, Read},
+    os::{
+        fd::{AsFd, BorrowedFd, OwnedFd},
+        unix::fs::OpenOptionsExt as _,
+    },
+    path::Path,
+    time::Duration,
+};
+
+use nix::{errno::Errno, unistd::read};
+
+use crate::fd::{nonblocking_flag, wait_readable};
+pub use crate::ts::{TS_PACKET_SIZE, TS_SYNC_BYTE};
+
+/// How many packets are read from the device at once.
+const PACKETS_PER_READ: usize = 256;
+
+/// An opened DVR device, such as `/dev/dvb/adapter0/dvr0`.
+///
+/// Reading gives the transport stream as a stream of bytes, with no guarantee to be aligned on packets.
+/// Use [Dvr::packets] to get whole packets, which can be inspected as a [TsPacket](crate::ts::TsPacket).
+#[derive(Debug)]
+pub struct Dvr {
+    fd: OwnedFd,
+}
+
+impl Dvr {
+    /// Opens the DVR at given path for reading.
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open(path: impl AsRef<Path>, nonblocking: bool) -> io::Result<Dvr> {
+        let file = OpenOptions::new()
+            .read(true)
+            .custom_flags(nonblocking_flag(nonblocking))
+            .open(path)?;
+        Ok(Dvr { fd: file.into() })
+    }
+
+    /// Reads into `buf`, waiting at most `timeout` for data to come.
+    ///
+    /// Fails with [io::ErrorKind::TimedOut] if no data came in time, which means the stream stalled.
+    pub fn read_timeout(&self, buf: &mut [u8], timeout: Duration) -> io::Result<usize> {
+        if !wait_readable(self.fd.as_fd(), timeout)? {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "no data from DVR within timeout",
+            ));
+        }
+        Ok(read(&self.fd, buf)?)
+    }
+
+    /// Iterates over transport stream packets, re-aligning on the sync byte whenever the stream is not aligned.
+    ///
+    /// Bytes that are not part of an aligned packet are dropped. The iteration ends when the device reports end of file.
+    pub fn packets(&self) -> Packets<'_> {
+        Packets {
+            dvr: self,
+            buf: vec![0; TS_PACKET_SIZE * PACKETS_PER_READ],
+            start: 0,
+            end: 0,
+        }
+    }
+}
+
+impl Read for Dvr {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self).read(buf)
+    }
+}
+
+impl Read for &Dvr {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Ok(read(&self.fd, buf)?)
+    }
+}
+
+impl AsFd for Dvr {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<OwnedFd> for Dvr {
+    fn from(fd: OwnedFd) -> Self {
+        Dvr { fd }
+    }
+}
+
+impl From<Dvr> for OwnedFd {
+    fn from(dvr: Dvr) -> Self {
+        dvr.fd
+    }
+}
+
+/// Iterator over the packets of a [Dvr], see [Dvr::packets].
+#[derive(Debug)]
+pub struct Packets<'a> {
+    dvr: &'a Dvr,
+    buf: Vec<u8>,
+    start: usize,
+    end: usize,
+}
+
+impl Packets<'_> {
+    /// Reads more data from the device, after moving leftover bytes to the front. Returns false on end of file.
+    fn fill(&mut self) -> io::Result<bool> {
+        self.buf.copy_within(self.start..self.end, 0);
+        self.end -= self.start;
+        self.start = 0;
+
+        loop {
+            match read(&self.dvr.fd, &mut self.buf[self.end..]) {
+                Ok(0) => return Ok(false),
+                Ok(len) => {
+                    self.end += len;
+                    return Ok(true);
+                }
+                Err(Errno::EINTR) => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Whether a packet starting at given position looks aligned while resyncing: it starts with the sync byte,
+    /// and so does the next packet if it was already read. This avoids locking onto a 0x47 inside a payload.
+    fn aligned_at(&self, pos: usize) -> bool {
+        let next = pos + TS_PACKET_SIZE;
+        self.buf[pos] == TS_SYNC_BYTE && (next >= self.end || self.buf[next] == TS_SYNC_BYTE)
+    }
+}
+
+impl Iterator for Packets<'_> {
+    type Item = io::Result<[u8; TS_PACKET_SIZE]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.end - self.start < TS_PACKET_SIZE {
+                match self.fill() {
+                    Ok(true) => continue,
+                    Ok(false) => return None,
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+
+            if self.buf[self.start] != TS_SYNC_BYTE {
+                // Skip to the next plausible packet start, or drop everything but the last bytes if there is none yet
+                let last = self.end - TS_PACKET_SIZE;
+                self.start = (self.start + 1..=last)
+                    .find(|&pos| self.aligned_at(pos))
+                    .unwrap_or(last + 1);
+                continue;
+            }
+
+            let mut packet = [0; TS_PACKET_SIZE];
+            packet.copy_from_slice(&self.buf[self.start..self.start + TS_PACKET_SIZE]);
+            self.start += TS_PACKET_SIZE;
+            return Some(Ok(packet));
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use std::ffi::c_int;
+use std::{ffi::c_int, io, path::PathBuf};
 
 use nix::errno::Errno;
 use thiserror::Error;
 
+use crate::frontend::data::{FeCodeRate, FeDeliverySystem, FeModulation};
+
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/dmx-start.html#return-value))
 #[derive(Error, Debug)]
 pub enum DmxStartError {
@@ -31,6 +33,9 @@ impl From<Errno> for DmxStartError {
 pub enum DmxSetPesFilterError {
     #[error("already filtering from another input source")]
     Conflicting,
+    /// No PID was given to filter, see [Demux::set_pid_filter_ts](crate::demux::wrapper::Demux::set_pid_filter_ts).
+    #[error("no PID to filter")]
+    NoPids,
     #[error("undefined error from ioctl")]
     Undefined(Errno),
 }
@@ -44,20 +49,246 @@ impl From<Errno> for DmxSetPesFilterError {
     }
 }
 
+/// Errors when reading filtered data from a demux.
+#[derive(Error, Debug)]
+pub enum DmxReadError {
+    /// Data was not read fast enough and the kernel buffer was reset, losing data.
+    ///
+    /// The filter keeps running. If this happens often, the buffer size should be increased.
+    #[error("demux buffer overflowed, data was lost")]
+    Overflow,
+    #[error("no data came within the timeout")]
+    Timeout,
+    #[error("could not set the filter")]
+    SetFilter(Errno),
+    #[error("undefined error from read")]
+    Undefined(Errno),
+}
+
+impl From<Errno> for DmxReadError {
+    fn from(value: Errno) -> Self {
+        match value {
+            Errno::EOVERFLOW => DmxReadError::Overflow,
+            Errno::ETIMEDOUT => DmxReadError::Timeout,
+            e => DmxReadError::Undefined(e),
+        }
+    }
+}
+
+/// Errors when splitting received bytes into sections.
+#[derive(Error, Debug)]
+pub enum SectionError {
+    #[error("CRC32 mismatch in a section of table {table_id:#04x}")]
+    CrcMismatch { table_id: u8 },
+    /// The section length field is larger than any section can be, bytes were probably lost.
+    #[error("section of {0} bytes is larger than the maximum")]
+    TooLong(usize),
+    #[error("section of {0} bytes is too short to hold a CRC32")]
+    TooShort(usize),
+}
+
+/// Errors when reading the PAT or a PMT.
+#[derive(Error, Debug)]
+pub enum PsiError {
+    #[error("section is too short or its lengths are inconsistent")]
+    Malformed,
+    #[error("expected table {expected:#04x}, found {found:#04x}")]
+    UnexpectedTable { expected: u8, found: u8 },
+    #[error("program {0} is not in the PAT")]
+    ProgramNotFound(u16),
+    #[error("could not set the section filter")]
+    Filter(Errno),
+    #[error("problem while reading a section")]
+    Read(#[from] DmxReadError),
+}
+
+/// Errors of FE_GET_INFO, telling a frontend in use apart from a broken one.
+#[derive(Error, Debug)]
+pub enum FrontendInfoError {
+    /// Another process has the frontend.
+    #[error("frontend is in use by another process")]
+    Busy,
+    /// The device does not behave like a DVB frontend.
+    #[error("device does not support querying frontend information")]
+    NotSupported,
+    #[error("undefined error from ioctl")]
+    Undefined(Errno),
+}
+
+impl From<Errno> for FrontendInfoError {
+    fn from(value: Errno) -> Self {
+        match value {
+            Errno::EBUSY => FrontendInfoError::Busy,
+            Errno::ENOTTY | Errno::EOPNOTSUPP => FrontendInfoError::NotSupported,
+            e => FrontendInfoError::Undefined(e),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum WaitError {
+    #[error("no event within the timeout")]
+    Timeout,
+    #[error("problem while waiting for an event")]
+    Poll(Errno),
+    /// Events were lost because they were not read fast enough. The next event can be waited for normally.
+    #[error("frontend event queue overflowed")]
+    Overflow,
+    #[error("problem while reading frontend event")]
+    GetEvent(Errno),
+}
+
 #[derive(Error, Debug)]
 pub enum PropertyError {
-    #[error("requested too many parameters at once")]
-    TooManyParameters,
+    #[error("requested {requested} properties, max is {max}")]
+    TooManyParameters { requested: usize, max: usize },
     #[error("problem while reading one or more properties")]
     GetProperty(Errno),
     #[error("problem while writing one or more properties")]
     SetProperty(Errno),
+    #[error("frontend was opened read-only, properties cannot be written")]
+    ReadOnly,
 }
 
 #[derive(Error, Debug)]
 pub enum DtvError {
     #[error("tried to receive information from a query that wasn't ran")]
     NotRan,
-    #[error("kernel application returned an error")]
+    /// The kernel reported an error in the result field of the property, as a negative errno.
+    #[error("kernel application returned an error: {}", reported_errno(.0))]
     Reported(c_int),
+    #[error("problem while sending or receiving properties")]
+    Property(#[from] PropertyError),
+    #[error("problem while reading frontend status")]
+    ReadStatus(Errno),
+    #[error("problem while setting up the antenna (voltage, tone or DiSEqC)")]
+    Sec(Errno),
+    /// The driver accepted the delivery system without switching to it.
+    #[error("asked for delivery system {requested}, but frontend uses {active}")]
+    DeliverySystemRejected {
+        requested: FeDeliverySystem,
+        active: FeDeliverySystem,
+    },
+}
+
+impl DtvError {
+    /// Decodes the error reported by the kernel, if this is a [DtvError::Reported].
+    pub fn errno(&self) -> Option<Errno> {
+        match self {
+            DtvError::Reported(result) => Some(reported_errno(result)),
+            _ => None,
+        }
+    }
+}
+
+/// The kernel reports errors in property results as negative errno values (-EINVAL...).
+fn reported_errno(result: &c_int) -> Errno {
+    Errno::from_raw(result.saturating_neg())
+}
+
+#[derive(Error, Debug)]
+pub enum DevicesError {
+    #[error("could not list DVB devices in sysfs")]
+    ReadDir(io::Error),
+    #[error("unexpected DVB device name in sysfs: {0:?}")]
+    UnexpectedName(PathBuf),
+}
+
+#[derive(Error, Debug)]
+pub enum VdrParseError {
+    #[error("expected 13 fields in channel line, found {0}")]
+    FieldCount(usize),
+    #[error("invalid {0}: {1:?}")]
+    InvalidNumber(&'static str, String),
+    #[error("invalid value for parameter {0}: {1:?}")]
+    InvalidParameter(char, String),
+    #[error("unsupported source: {0:?}")]
+    UnsupportedSource(String),
+}
+
+#[derive(Error, Debug)]
+pub enum Dvbv5ParseError {
+    #[error("expected a [channel] or a KEY = VALUE line: {0:?}")]
+    MalformedLine(String),
+    #[error("property outside of a channel section: {0:?}")]
+    OutsideChannel(String),
+    #[error("invalid value for {0}: {1:?}")]
+    InvalidValue(String, String),
+    /// The frequency of satellite channels is the one of the transponder, it cannot be tuned without an LNB setup.
+    #[error("channel {channel:?} uses {system}, which needs an LNB to be tuned")]
+    Satellite {
+        channel: String,
+        system: FeDeliverySystem,
+    },
+}
+
+/// A tune request asks for something the frontend does not advertise in its capabilities.
+///
+/// Drivers do not always fill their capabilities accurately, so this is a strong hint rather than a certainty.
+#[derive(Error, Debug)]
+pub enum UnsupportedCap {
+    #[error("could not read frontend capabilities")]
+    GetInfo(#[from] FrontendInfoError),
+    #[error("frontend does not support modulation {0}")]
+    Modulation(FeModulation),
+    #[error("frontend does not support code rate {0:?}")]
+    CodeRate(FeCodeRate),
+    #[error("frontend cannot detect {0} automatically")]
+    Auto(&'static str),
+    #[error("frontend does not support second generation delivery systems")]
+    SecondGeneration,
+    #[error("frontend does not support multistream")]
+    Multistream,
+}
+
+/// A tuning parameter lies outside the range advertised by the frontend.
+#[derive(Error, Debug)]
+pub enum RangeError {
+    #[error("could not read frontend ranges")]
+    GetInfo(#[from] FrontendInfoError),
+    #[error("{parameter} {value} is out of range, frontend supports {min} to {max}")]
+    OutOfRange {
+        parameter: &'static str,
+        value: u32,
+        min: u32,
+        max: u32,
+    },
+}
+
+/// A tune request builder was not given enough, or conflicting, parameters.
+#[derive(Error, Debug)]
+pub enum TuneRequestError {
+    #[error("frequency is required to tune")]
+    MissingFrequency,
+    #[error("symbol rate is required to tune")]
+    MissingSymbolRate,
+    #[error("polarization is required to tune")]
+    MissingPolarization,
+    /// A parameter was set that has no meaning for the delivery system of the request.
+    #[error("{parameter} cannot be used with {system}")]
+    InvalidForDeliverySystem {
+        parameter: &'static str,
+        system: FeDeliverySystem,
+    },
+    /// An ISDB-Tsb segment index was set that is not smaller than the number of connected segments.
+    #[error("segment index {index} is out of the {count} connected segments")]
+    SegmentOutOfRange { index: u8, count: u8 },
+}
+
+/// A name given as text (on a command line, in a config file...) does not match any known value.
+#[derive(Error, Debug)]
+#[error("unknown {kind}: {name:?}")]
+pub struct UnknownNameError {
+    pub kind: &'static str,
+    pub name: String,
+}
+
+#[derive(Error, Debug)]
+pub enum ScanError {
+    #[error("could not set frontend tune mode")]
+    TuneMode(Errno),
+    #[error("problem while tuning")]
+    Tune(#[from] DtvError),
+    #[error("invalid tune request")]
+    Request(#[from] TuneRequestError),
 }
@@ -0,0 +1,38 @@
+//! Helpers working on any device file descriptor, shared by the device wrappers
+
+use std::{io, os::fd::BorrowedFd, time::Duration};
+
+use nix::{
+    errno::Errno,
+    fcntl::{FcntlArg, OFlag, fcntl},
+    poll::{PollFd, PollFlags, PollTimeout, poll},
+};
+
+/// Sets or clears `O_NONBLOCK` on an already opened file descriptor.
+pub(crate) fn set_nonblocking(fd: BorrowedFd, nonblocking: bool) -> io::Result<()> {
+    let flags = OFlag::from_bits_retain(fcntl(fd, FcntlArg::F_GETFL)?);
+    let new_flags = if nonblocking {
+        flags | OFlag::O_NONBLOCK
+    } else {
+        flags - OFlag::O_NONBLOCK
+    };
+    if new_flags != flags {
+        fcntl(fd, FcntlArg::F_SETFL(new_flags))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn nonblocking_flag(nonblocking: bool) -> i32 {
+    if nonblocking {
+        OFlag::O_NONBLOCK.bits()
+    } else {
+        0
+    }
+}
+
+/// Waits until given file descriptor has data to read (or a frontend event), returning false if nothing came within `timeout`.
+pub(crate) fn wait_readable(fd: BorrowedFd, timeout: Duration) -> Result<bool, Errno> {
+    let timeout = PollTimeout::try_from(timeout).unwrap_or(PollTimeout::MAX);
+    let mut fds = [PollFd::new(fd, PollFlags::POLLIN | PollFlags::POLLPRI)];
+    Ok(poll(&mut fds, timeout)? > 0)
+}
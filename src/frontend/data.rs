@@ -1,23 +1,33 @@
-use std::{
+use core::{
     ffi::{c_char, c_uint},
     fmt,
 };
+#[cfg(feature = "std")]
+use std::{borrow::Cow, str::FromStr};
 
 use enum_from_discriminant_derive::TryFromDiscriminant;
 
+#[cfg(feature = "std")]
+use crate::error::UnknownNameError;
+
 //
 // ----- Constants
 
 pub const DTV_IOCTL_MAX_MSGS: usize = 64;
 
+/// Flag for FE_SET_FRONTEND_TUNE_MODE: the frontend will not try to zigzag around the tuned frequency.
+pub const FE_TUNE_MODE_ONESHOT: u32 = 0x01;
+
 //
 // ----- Frontend Info
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct DvbFrontendInfo {
     pub name: [c_char; 128],
-    pub type_: FeType,
+    /// Legacy frontend type, see [DvbFrontendInfo::fe_type]. Kept as an integer so that any value from the kernel is valid.
+    pub type_: u32,
     pub frequency_min: u32,
     pub frequency_max: u32,
     pub frequency_stepsize: u32,
@@ -29,12 +39,71 @@ pub struct DvbFrontendInfo {
     pub caps: FeCaps,
 }
 
+impl DvbFrontendInfo {
+    /// Legacy type of the frontend, `None` if the kernel reported an unknown value.
+    pub fn fe_type(&self) -> Option<FeType> {
+        FeType::try_from(self.type_).ok()
+    }
+
+    /// Capabilities of the frontend, decoded from [DvbFrontendInfo::caps].
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::from(self.caps)
+    }
+
+    /// Name of the frontend, up to the NUL terminator. Invalid UTF-8 is replaced.
+    #[cfg(feature = "std")]
+    pub fn name(&self) -> Cow<'_, str> {
+        // SAFETY: c_char and u8 have the same size and alignment, and the slice stays within the array.
+        let bytes: &[u8] =
+            unsafe { core::slice::from_raw_parts(self.name.as_ptr().cast(), self.name.len()) };
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..len])
+    }
+}
+
+//
+// ----- DiSEqC
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_diseqc_master_cmd))
+///
+/// DiSEqC master command
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct DvbDiseqcMasterCmd {
+    /// DiSEqC message to be sent. It contains a 3 bytes header with: framing + address + command, and an optional argument of up to 3 bytes of data.
+    pub msg: [u8; 6],
+    /// Length of the DiSEqC message. Valid values are 3 to 6.
+    pub msg_len: u8,
+}
+
+impl DvbDiseqcMasterCmd {
+    /// Creates a command from raw bytes. Panics if there are less than 3 or more than 6 bytes.
+    pub fn new(bytes: &[u8]) -> DvbDiseqcMasterCmd {
+        assert!(
+            (3..=6).contains(&bytes.len()),
+            "DiSEqC messages are 3 to 6 bytes long"
+        );
+        let mut msg = [0; 6];
+        msg[..bytes.len()].copy_from_slice(bytes);
+        DvbDiseqcMasterCmd {
+            msg,
+            msg_len: bytes.len() as u8,
+        }
+    }
+
+    /// Bytes of the message, without unused padding.
+    pub fn bytes(&self) -> &[u8] {
+        &self.msg[..(self.msg_len as usize).min(self.msg.len())]
+    }
+}
+
 //
 // ----- Status
 
 // TODO: Replace with https://github.com/meithecatte/enumflags2 or similar
 // TODO: Is FeStatus actually u32 ?
 // TODO: This really isn't sys anymore. Either I can re-export the type or move the entire thing up
+#[derive(Copy, Clone)]
 pub struct FeStatus(u32);
 
 impl From<c_uint> for FeStatus {
@@ -44,7 +113,7 @@ impl From<c_uint> for FeStatus {
 }
 
 impl fmt::Debug for FeStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("FeStatus")
             .field("Has Signal", &self.has_signal())
             .field("Has Carrier", &self.has_carrier())
@@ -57,6 +126,35 @@ impl fmt::Debug for FeStatus {
     }
 }
 
+/// Compact form listing only set bits, such as `SIGNAL|CARRIER|VITERBI|SYNC|LOCK`, or `NONE`.
+impl fmt::Display for FeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.none() {
+            return write!(f, "NONE");
+        }
+
+        let bits = [
+            (self.has_signal(), "SIGNAL"),
+            (self.has_carrier(), "CARRIER"),
+            (self.has_viterbi(), "VITERBI"),
+            (self.has_sync(), "SYNC"),
+            (self.has_lock(), "LOCK"),
+            (self.timed_out(), "TIMEDOUT"),
+            (self.reinit(), "REINIT"),
+        ];
+        let names = bits
+            .into_iter()
+            .filter_map(|(set, name)| set.then_some(name));
+        for (i, name) in names.enumerate() {
+            if i > 0 {
+                write!(f, "|")?;
+            }
+            write!(f, "{}", name)?;
+        }
+        Ok(())
+    }
+}
+
 impl FeStatus {
     // const NONE: u32 = 0;
     const HAS_SIGNAL_BIT: u32 = 1;
@@ -108,31 +206,461 @@ impl FeStatus {
     }
 }
 
+//
+// ----- Legacy parameters and events
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_qpsk_parameters))
+///
+/// QPSK parameters for DVB-S, part of the legacy API.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DvbQpskParameters {
+    /// Symbol rate, in symbols per second
+    pub symbol_rate: u32,
+    /// Forward error correction, as a [FeCodeRate]
+    pub fec_inner: u32,
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_qam_parameters))
+///
+/// QAM parameters for DVB-C, part of the legacy API.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DvbQamParameters {
+    /// Symbol rate, in symbols per second
+    pub symbol_rate: u32,
+    /// Forward error correction, as a [FeCodeRate]
+    pub fec_inner: u32,
+    /// Modulation type, as a [FeModulation]
+    pub modulation: u32,
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_vsb_parameters))
+///
+/// VSB parameters for ATSC, part of the legacy API.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DvbVsbParameters {
+    /// Modulation type, as a [FeModulation]
+    pub modulation: u32,
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_ofdm_parameters))
+///
+/// OFDM parameters for DVB-T, part of the legacy API.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DvbOfdmParameters {
+    /// Bandwidth, as a legacy fe_bandwidth value
+    pub bandwidth: u32,
+    /// High priority stream code rate, as a [FeCodeRate]
+    pub code_rate_hp: u32,
+    /// Low priority stream code rate, as a [FeCodeRate]
+    pub code_rate_lp: u32,
+    /// Modulation type, as a [FeModulation]
+    pub constellation: u32,
+    /// Transmission mode, as a [FeTransmitMode]
+    pub transmission_mode: u32,
+    /// Guard interval, as a [FeGuardInterval]
+    pub guard_interval: u32,
+    /// Hierarchy, as a [FeHierarchy]
+    pub hierarchy_information: u32,
+}
+
+/// Parameters specific to each legacy frontend type.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union DvbFrontendParametersUnion {
+    pub qpsk: DvbQpskParameters,
+    pub qam: DvbQamParameters,
+    pub ofdm: DvbOfdmParameters,
+    pub vsb: DvbVsbParameters,
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_frontend_parameters))
+///
+/// Legacy tuning parameters, superseded by properties. Which field of the union is valid depends on the [FeType] of the frontend.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DvbFrontendParameters {
+    /// Frequency, in kHz for satellite and in Hz for other systems
+    pub frequency: u32,
+    /// Spectral inversion, as a [FeSpectralInversion]
+    pub inversion: u32,
+    pub u: DvbFrontendParametersUnion,
+}
+
+impl DvbFrontendParameters {
+    /// Legacy parameters for a DVB-S (`FE_QPSK`) frontend. Frequency is in kHz.
+    pub fn qpsk(frequency: u32, inversion: FeSpectralInversion, qpsk: DvbQpskParameters) -> Self {
+        let mut params = DvbFrontendParameters::new(frequency, inversion);
+        params.u.qpsk = qpsk;
+        params
+    }
+
+    /// Legacy parameters for a DVB-C (`FE_QAM`) frontend. Frequency is in Hz.
+    pub fn qam(frequency: u32, inversion: FeSpectralInversion, qam: DvbQamParameters) -> Self {
+        let mut params = DvbFrontendParameters::new(frequency, inversion);
+        params.u.qam = qam;
+        params
+    }
+
+    /// Legacy parameters for a DVB-T (`FE_OFDM`) frontend. Frequency is in Hz.
+    pub fn ofdm(frequency: u32, inversion: FeSpectralInversion, ofdm: DvbOfdmParameters) -> Self {
+        let mut params = DvbFrontendParameters::new(frequency, inversion);
+        params.u.ofdm = ofdm;
+        params
+    }
+
+    /// Legacy parameters for an ATSC (`FE_ATSC`) frontend. Frequency is in Hz.
+    pub fn vsb(frequency: u32, inversion: FeSpectralInversion, vsb: DvbVsbParameters) -> Self {
+        let mut params = DvbFrontendParameters::new(frequency, inversion);
+        params.u.vsb = vsb;
+        params
+    }
+
+    /// Zeroed parameters, the caller then writes the variant in place so that the rest of the union stays zeroed.
+    fn new(frequency: u32, inversion: FeSpectralInversion) -> Self {
+        // SAFETY: All fields are plain integers, for which zero is valid.
+        let mut params: DvbFrontendParameters = unsafe { core::mem::zeroed() };
+        params.frequency = frequency;
+        params.inversion = inversion as u32;
+        params
+    }
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.dvb_frontend_event))
+///
+/// Event reported by FE_GET_EVENT, when the status of the frontend changed.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DvbFrontendEvent {
+    /// Status bits, see [DvbFrontendEvent::status]
+    pub status: c_uint,
+    /// Parameters the frontend is tuned with, using the legacy API
+    pub parameters: DvbFrontendParameters,
+}
+
+impl DvbFrontendEvent {
+    /// Status of the frontend when the event was generated.
+    pub fn status(&self) -> FeStatus {
+        FeStatus::from(self.status)
+    }
+}
+
+impl fmt::Debug for DvbFrontendEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DvbFrontendEvent")
+            .field("status", &self.status())
+            .field("frequency", &self.parameters.frequency)
+            .finish_non_exhaustive()
+    }
+}
+
 //
 // ----- Data used in properties (and more)
 
+/// Legacy frontend type, reported by FE_GET_INFO.
+///
+/// It predates multistandard frontends and only tells one family of systems.
+/// Prefer [EnumerateDeliverySystems](crate::frontend::queries::get::EnumerateDeliverySystems) to know what a frontend supports.
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_type))
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub enum FeType {
+    /// Satellite
     FE_QPSK,
+    /// Cable
     FE_QAM,
+    /// Terrestrial
     FE_OFDM,
+    /// ATSC, or DVB-C Annex B
     FE_ATSC,
 }
 
+impl FeType {
+    /// Guesses the delivery systems a frontend of this type can work with, for code that only has the legacy type.
+    ///
+    /// Only a guess: a satellite frontend may not support DVB-S2, and a multistandard frontend only reports one type.
+    pub fn likely_delivery_systems(&self) -> &'static [FeDeliverySystem] {
+        match self {
+            FeType::FE_QPSK => &[FeDeliverySystem::DVBS, FeDeliverySystem::DVBS2],
+            FeType::FE_QAM => &[
+                FeDeliverySystem::DVBC_ANNEX_A,
+                FeDeliverySystem::DVBC_ANNEX_C,
+            ],
+            FeType::FE_OFDM => &[FeDeliverySystem::DVBT, FeDeliverySystem::DVBT2],
+            FeType::FE_ATSC => &[FeDeliverySystem::ATSC, FeDeliverySystem::DVBC_ANNEX_B],
+        }
+    }
+}
+
+/// Capabilities of a frontend, as a set of bits.
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_caps))
 // TODO: Is FeCaps actually u32 ?
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct FeCaps(u32);
-// TODO: FeCaps bits
-impl FeCaps {}
+
+impl FeCaps {
+    const CAN_INVERSION_AUTO_BIT: u32 = 0x1;
+    const CAN_FEC_1_2_BIT: u32 = 0x2;
+    const CAN_FEC_2_3_BIT: u32 = 0x4;
+    const CAN_FEC_3_4_BIT: u32 = 0x8;
+    const CAN_FEC_4_5_BIT: u32 = 0x10;
+    const CAN_FEC_5_6_BIT: u32 = 0x20;
+    const CAN_FEC_6_7_BIT: u32 = 0x40;
+    const CAN_FEC_7_8_BIT: u32 = 0x80;
+    const CAN_FEC_8_9_BIT: u32 = 0x100;
+    const CAN_FEC_AUTO_BIT: u32 = 0x200;
+    const CAN_QPSK_BIT: u32 = 0x400;
+    const CAN_QAM_16_BIT: u32 = 0x800;
+    const CAN_QAM_32_BIT: u32 = 0x1000;
+    const CAN_QAM_64_BIT: u32 = 0x2000;
+    const CAN_QAM_128_BIT: u32 = 0x4000;
+    const CAN_QAM_256_BIT: u32 = 0x8000;
+    const CAN_QAM_AUTO_BIT: u32 = 0x10000;
+    const CAN_TRANSMISSION_MODE_AUTO_BIT: u32 = 0x20000;
+    const CAN_BANDWIDTH_AUTO_BIT: u32 = 0x40000;
+    const CAN_GUARD_INTERVAL_AUTO_BIT: u32 = 0x80000;
+    const CAN_HIERARCHY_AUTO_BIT: u32 = 0x100000;
+    const CAN_8VSB_BIT: u32 = 0x200000;
+    const CAN_16VSB_BIT: u32 = 0x400000;
+    const HAS_EXTENDED_CAPS_BIT: u32 = 0x800000;
+    const CAN_MULTISTREAM_BIT: u32 = 0x4000000;
+    const CAN_TURBO_FEC_BIT: u32 = 0x8000000;
+    const CAN_2G_MODULATION_BIT: u32 = 0x10000000;
+    const NEEDS_BENDING_BIT: u32 = 0x20000000;
+    const CAN_RECOVER_BIT: u32 = 0x40000000;
+    const CAN_MUTE_TS_BIT: u32 = 0x80000000;
+
+    fn has(&self, bit: u32) -> bool {
+        (self.0 & bit) != 0
+    }
+
+    /// "There's something wrong at the frontend, and it can't report its capabilities."
+    pub fn is_stupid(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// "Can auto-detect frequency spectral band inversion."
+    pub fn can_inversion_auto(&self) -> bool {
+        self.has(Self::CAN_INVERSION_AUTO_BIT)
+    }
+
+    /// Whether the frontend reports supporting this code rate.
+    ///
+    /// Code rates without a dedicated bit (all those introduced with DVB-S2 and DVB-T2) are covered by [FeCaps::can_2g_modulation].
+    /// ``FEC_NONE`` is always supported.
+    pub fn can_fec(&self, rate: FeCodeRate) -> bool {
+        match rate {
+            FeCodeRate::FEC_NONE => true,
+            FeCodeRate::FEC_1_2 => self.has(Self::CAN_FEC_1_2_BIT),
+            FeCodeRate::FEC_2_3 => self.has(Self::CAN_FEC_2_3_BIT),
+            FeCodeRate::FEC_3_4 => self.has(Self::CAN_FEC_3_4_BIT),
+            FeCodeRate::FEC_4_5 => self.has(Self::CAN_FEC_4_5_BIT),
+            FeCodeRate::FEC_5_6 => self.has(Self::CAN_FEC_5_6_BIT),
+            FeCodeRate::FEC_6_7 => self.has(Self::CAN_FEC_6_7_BIT),
+            FeCodeRate::FEC_7_8 => self.has(Self::CAN_FEC_7_8_BIT),
+            FeCodeRate::FEC_8_9 => self.has(Self::CAN_FEC_8_9_BIT),
+            FeCodeRate::FEC_AUTO => self.has(Self::CAN_FEC_AUTO_BIT),
+            _ => self.can_2g_modulation(),
+        }
+    }
+
+    /// Whether the frontend reports supporting this modulation.
+    ///
+    /// Modulations without a dedicated bit (8PSK, APSK...) are covered by [FeCaps::can_2g_modulation].
+    pub fn can_modulation(&self, modulation: FeModulation) -> bool {
+        match modulation {
+            FeModulation::QPSK => self.has(Self::CAN_QPSK_BIT),
+            FeModulation::QAM_16 => self.has(Self::CAN_QAM_16_BIT),
+            FeModulation::QAM_32 => self.has(Self::CAN_QAM_32_BIT),
+            FeModulation::QAM_64 => self.has(Self::CAN_QAM_64_BIT),
+            FeModulation::QAM_128 => self.has(Self::CAN_QAM_128_BIT),
+            FeModulation::QAM_256 => self.has(Self::CAN_QAM_256_BIT),
+            FeModulation::QAM_AUTO => self.has(Self::CAN_QAM_AUTO_BIT),
+            FeModulation::VSB_8 => self.has(Self::CAN_8VSB_BIT),
+            FeModulation::VSB_16 => self.has(Self::CAN_16VSB_BIT),
+            _ => self.can_2g_modulation(),
+        }
+    }
+
+    /// "Can auto-detect transmission mode."
+    pub fn can_transmission_mode_auto(&self) -> bool {
+        self.has(Self::CAN_TRANSMISSION_MODE_AUTO_BIT)
+    }
+
+    /// "Can auto-detect bandwidth."
+    pub fn can_bandwidth_auto(&self) -> bool {
+        self.has(Self::CAN_BANDWIDTH_AUTO_BIT)
+    }
+
+    /// "Can auto-detect guard interval."
+    pub fn can_guard_interval_auto(&self) -> bool {
+        self.has(Self::CAN_GUARD_INTERVAL_AUTO_BIT)
+    }
+
+    /// "Can auto-detect hierarchy."
+    pub fn can_hierarchy_auto(&self) -> bool {
+        self.has(Self::CAN_HIERARCHY_AUTO_BIT)
+    }
+
+    /// "Unused."
+    pub fn has_extended_caps(&self) -> bool {
+        self.has(Self::HAS_EXTENDED_CAPS_BIT)
+    }
+
+    /// "Supports multistream filtering."
+    pub fn can_multistream(&self) -> bool {
+        self.has(Self::CAN_MULTISTREAM_BIT)
+    }
+
+    /// "Supports "turbo FEC" modulation."
+    pub fn can_turbo_fec(&self) -> bool {
+        self.has(Self::CAN_TURBO_FEC_BIT)
+    }
+
+    /// "Supports "2nd generation" modulation, e. g. DVB-S2, DVB-T2, DVB-C2."
+    pub fn can_2g_modulation(&self) -> bool {
+        self.has(Self::CAN_2G_MODULATION_BIT)
+    }
+
+    /// "Unused."
+    pub fn needs_bending(&self) -> bool {
+        self.has(Self::NEEDS_BENDING_BIT)
+    }
+
+    /// "Can recover from a cable unplug automatically."
+    pub fn can_recover(&self) -> bool {
+        self.has(Self::CAN_RECOVER_BIT)
+    }
+
+    /// "Can stop spurious TS data output."
+    pub fn can_mute_ts(&self) -> bool {
+        self.has(Self::CAN_MUTE_TS_BIT)
+    }
+}
+
+/// Capabilities of a frontend decoded as booleans, easier to read and print than [FeCaps].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    pub can_inversion_auto: bool,
+    pub can_fec_1_2: bool,
+    pub can_fec_2_3: bool,
+    pub can_fec_3_4: bool,
+    pub can_fec_4_5: bool,
+    pub can_fec_5_6: bool,
+    pub can_fec_6_7: bool,
+    pub can_fec_7_8: bool,
+    pub can_fec_8_9: bool,
+    pub can_fec_auto: bool,
+    pub can_qpsk: bool,
+    pub can_qam_16: bool,
+    pub can_qam_32: bool,
+    pub can_qam_64: bool,
+    pub can_qam_128: bool,
+    pub can_qam_256: bool,
+    pub can_qam_auto: bool,
+    pub can_transmission_mode_auto: bool,
+    pub can_bandwidth_auto: bool,
+    pub can_guard_interval_auto: bool,
+    pub can_hierarchy_auto: bool,
+    pub can_8vsb: bool,
+    pub can_16vsb: bool,
+    pub can_multistream: bool,
+    pub can_turbo_fec: bool,
+    pub can_2g_modulation: bool,
+    pub can_recover: bool,
+    pub can_mute_ts: bool,
+}
+
+impl From<FeCaps> for Capabilities {
+    fn from(caps: FeCaps) -> Self {
+        Capabilities {
+            can_inversion_auto: caps.can_inversion_auto(),
+            can_fec_1_2: caps.has(FeCaps::CAN_FEC_1_2_BIT),
+            can_fec_2_3: caps.has(FeCaps::CAN_FEC_2_3_BIT),
+            can_fec_3_4: caps.has(FeCaps::CAN_FEC_3_4_BIT),
+            can_fec_4_5: caps.has(FeCaps::CAN_FEC_4_5_BIT),
+            can_fec_5_6: caps.has(FeCaps::CAN_FEC_5_6_BIT),
+            can_fec_6_7: caps.has(FeCaps::CAN_FEC_6_7_BIT),
+            can_fec_7_8: caps.has(FeCaps::CAN_FEC_7_8_BIT),
+            can_fec_8_9: caps.has(FeCaps::CAN_FEC_8_9_BIT),
+            can_fec_auto: caps.has(FeCaps::CAN_FEC_AUTO_BIT),
+            can_qpsk: caps.has(FeCaps::CAN_QPSK_BIT),
+            can_qam_16: caps.has(FeCaps::CAN_QAM_16_BIT),
+            can_qam_32: caps.has(FeCaps::CAN_QAM_32_BIT),
+            can_qam_64: caps.has(FeCaps::CAN_QAM_64_BIT),
+            can_qam_128: caps.has(FeCaps::CAN_QAM_128_BIT),
+            can_qam_256: caps.has(FeCaps::CAN_QAM_256_BIT),
+            can_qam_auto: caps.has(FeCaps::CAN_QAM_AUTO_BIT),
+            can_transmission_mode_auto: caps.can_transmission_mode_auto(),
+            can_bandwidth_auto: caps.can_bandwidth_auto(),
+            can_guard_interval_auto: caps.can_guard_interval_auto(),
+            can_hierarchy_auto: caps.can_hierarchy_auto(),
+            can_8vsb: caps.has(FeCaps::CAN_8VSB_BIT),
+            can_16vsb: caps.has(FeCaps::CAN_16VSB_BIT),
+            can_multistream: caps.can_multistream(),
+            can_turbo_fec: caps.can_turbo_fec(),
+            can_2g_modulation: caps.can_2g_modulation(),
+            can_recover: caps.can_recover(),
+            can_mute_ts: caps.can_mute_ts(),
+        }
+    }
+}
+
+/// DC Voltage used to feed the LNBf
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_sec_voltage))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum SecVoltage {
+    /// Output 13V to the LNBf
+    SEC_VOLTAGE_13,
+    /// Output 18V to the LNBf
+    SEC_VOLTAGE_18,
+    /// Don't feed the LNBf with a DC voltage
+    SEC_VOLTAGE_OFF,
+}
+
+/// Type of tone
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_sec_tone_mode))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum SecToneMode {
+    /// Sends a 22kHz tone burst to the antenna.
+    SEC_TONE_ON,
+    /// Don't send a 22kHz tone to the antenna (except if the ``FE_DISEQC_*`` ioctls are called).
+    SEC_TONE_OFF,
+}
+
+/// Type of mini sequence (tone burst)
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_sec_mini_cmd))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum SecMiniCmd {
+    /// Sends a mini-DiSEqC 22kHz '0' Tone Burst to select satellite-A
+    SEC_MINI_A = 0,
+    /// Sends a mini-DiSEqC 22kHz '1' Data Burst to select satellite-B
+    SEC_MINI_B = 1,
+}
 
 /// Type of the delivery system
 ///
 /// (from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_delivery_system))
 #[repr(C)]
 #[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum FeDeliverySystem {
     /// Undefined standard. Generally, indicates an error
@@ -177,55 +705,223 @@ pub enum FeDeliverySystem {
     DVBC2,
 }
 
+impl FeDeliverySystem {
+    /// Whether this is a satellite delivery system, for which frequencies are given in kHz instead of Hz.
+    pub fn is_satellite(&self) -> bool {
+        matches!(
+            self,
+            FeDeliverySystem::DSS
+                | FeDeliverySystem::DVBS
+                | FeDeliverySystem::DVBS2
+                | FeDeliverySystem::ISDBS
+                | FeDeliverySystem::TURBO
+        )
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FeDeliverySystem::UNDEFINED => "UNDEFINED",
+            FeDeliverySystem::DVBC_ANNEX_A => "DVB-C/ANNEX_A",
+            FeDeliverySystem::DVBC_ANNEX_B => "DVB-C/ANNEX_B",
+            FeDeliverySystem::DVBT => "DVB-T",
+            FeDeliverySystem::DSS => "DSS",
+            FeDeliverySystem::DVBS => "DVB-S",
+            FeDeliverySystem::DVBS2 => "DVB-S2",
+            FeDeliverySystem::DVBH => "DVB-H",
+            FeDeliverySystem::ISDBT => "ISDB-T",
+            FeDeliverySystem::ISDBS => "ISDB-S",
+            FeDeliverySystem::ISDBC => "ISDB-C",
+            FeDeliverySystem::ATSC => "ATSC",
+            FeDeliverySystem::ATSCMH => "ATSC-MH",
+            FeDeliverySystem::DTMB => "DTMB",
+            FeDeliverySystem::CMMB => "CMMB",
+            FeDeliverySystem::DAB => "DAB",
+            FeDeliverySystem::DVBT2 => "DVB-T2",
+            FeDeliverySystem::TURBO => "TURBO",
+            FeDeliverySystem::DVBC_ANNEX_C => "DVB-C/ANNEX_C",
+            FeDeliverySystem::DVBC2 => "DVB-C2",
+        }
+    }
+}
+
+impl fmt::Display for FeDeliverySystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Case-insensitive, and ignores separators: ``DVBT2``, ``DVB-T2`` and ``dvb_t2`` are all accepted.
+///
+/// ``DVBC`` is accepted as DVB-C Annex A.
+#[cfg(feature = "std")]
+impl FromStr for FeDeliverySystem {
+    type Err = UnknownNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match normalize_name(s).as_str() {
+            "UNDEFINED" => FeDeliverySystem::UNDEFINED,
+            "DVBCANNEXA" | "DVBC" => FeDeliverySystem::DVBC_ANNEX_A,
+            "DVBCANNEXB" => FeDeliverySystem::DVBC_ANNEX_B,
+            "DVBT" => FeDeliverySystem::DVBT,
+            "DSS" => FeDeliverySystem::DSS,
+            "DVBS" => FeDeliverySystem::DVBS,
+            "DVBS2" => FeDeliverySystem::DVBS2,
+            "DVBH" => FeDeliverySystem::DVBH,
+            "ISDBT" => FeDeliverySystem::ISDBT,
+            "ISDBS" => FeDeliverySystem::ISDBS,
+            "ISDBC" => FeDeliverySystem::ISDBC,
+            "ATSC" => FeDeliverySystem::ATSC,
+            "ATSCMH" => FeDeliverySystem::ATSCMH,
+            "DTMB" => FeDeliverySystem::DTMB,
+            "CMMB" => FeDeliverySystem::CMMB,
+            "DAB" => FeDeliverySystem::DAB,
+            "DVBT2" => FeDeliverySystem::DVBT2,
+            "TURBO" => FeDeliverySystem::TURBO,
+            "DVBCANNEXC" => FeDeliverySystem::DVBC_ANNEX_C,
+            "DVBC2" => FeDeliverySystem::DVBC2,
+            _ => {
+                return Err(UnknownNameError {
+                    kind: "delivery system",
+                    name: s.to_string(),
+                });
+            }
+        })
+    }
+}
+
+/// Uppercases and drops everything but letters and digits, so that ``dvb-t2`` and ``DVBT2`` compare equal.
+#[cfg(feature = "std")]
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
 /// Type of modulation/constellation
 ///
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_modulation))
 #[repr(C)]
-#[derive(Debug, Copy, Clone, TryFromDiscriminant)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum FeModulation {
     /// QPSK modulation
-    QPSK,
+    QPSK = 0,
     /// 16-QAM modulation
-    QAM_16,
+    QAM_16 = 1,
     /// 32-QAM modulation
-    QAM_32,
+    QAM_32 = 2,
     /// 64-QAM modulation
-    QAM_64,
+    QAM_64 = 3,
     /// 128-QAM modulation
-    QAM_128,
+    QAM_128 = 4,
     /// 256-QAM modulation
-    QAM_256,
+    QAM_256 = 5,
     /// Autodetect QAM modulation
-    QAM_AUTO,
+    QAM_AUTO = 6,
     /// 8-VSB modulation
-    VSB_8,
+    VSB_8 = 7,
     /// 16-VSB modulation
-    VSB_16,
+    VSB_16 = 8,
     /// 8-PSK modulation
-    PSK_8,
+    PSK_8 = 9,
     /// 16-APSK modulation
-    APSK_16,
+    APSK_16 = 10,
     /// 32-APSK modulation
-    APSK_32,
+    APSK_32 = 11,
     /// DQPSK modulation
-    DQPSK,
+    DQPSK = 12,
     /// 4-QAM-NR modulation
-    QAM_4_NR,
+    QAM_4_NR = 13,
     /// 1024-QAM modulation
-    QAM_1024,
+    QAM_1024 = 14,
     /// 4096-QAM modulation
-    QAM_4096,
+    QAM_4096 = 15,
     /// 8APSK-L modulation
-    APSK_8_L,
+    APSK_8_L = 16,
     /// 16APSK-L modulation
-    APSK_16_L,
+    APSK_16_L = 17,
     /// 32APSK-L modulation
-    APSK_32_L,
+    APSK_32_L = 18,
     /// 64APSK modulation
-    APSK_64,
+    APSK_64 = 19,
     /// 64APSK-L modulation
-    APSK_64_L,
+    APSK_64_L = 20,
+}
+
+impl FeModulation {
+    fn name(self) -> &'static str {
+        match self {
+            FeModulation::QPSK => "QPSK",
+            FeModulation::QAM_16 => "16-QAM",
+            FeModulation::QAM_32 => "32-QAM",
+            FeModulation::QAM_64 => "64-QAM",
+            FeModulation::QAM_128 => "128-QAM",
+            FeModulation::QAM_256 => "256-QAM",
+            FeModulation::QAM_AUTO => "QAM-AUTO",
+            FeModulation::VSB_8 => "8-VSB",
+            FeModulation::VSB_16 => "16-VSB",
+            FeModulation::PSK_8 => "8-PSK",
+            FeModulation::APSK_16 => "16-APSK",
+            FeModulation::APSK_32 => "32-APSK",
+            FeModulation::DQPSK => "DQPSK",
+            FeModulation::QAM_4_NR => "4-QAM-NR",
+            FeModulation::QAM_1024 => "1024-QAM",
+            FeModulation::QAM_4096 => "4096-QAM",
+            FeModulation::APSK_8_L => "8-APSK-L",
+            FeModulation::APSK_16_L => "16-APSK-L",
+            FeModulation::APSK_32_L => "32-APSK-L",
+            FeModulation::APSK_64 => "64-APSK",
+            FeModulation::APSK_64_L => "64-APSK-L",
+        }
+    }
+}
+
+impl fmt::Display for FeModulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Case-insensitive, ignores separators and where the order is given: ``QAM256``, ``QAM_256``, ``256QAM`` and ``256-qam`` are all accepted.
+#[cfg(feature = "std")]
+impl FromStr for FeModulation {
+    type Err = UnknownNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = normalize_name(s);
+        let (digits, letters): (String, String) = name.chars().partition(char::is_ascii_digit);
+        Ok(match (letters.as_str(), digits.as_str()) {
+            ("QPSK", "") => FeModulation::QPSK,
+            ("QAM", "16") => FeModulation::QAM_16,
+            ("QAM", "32") => FeModulation::QAM_32,
+            ("QAM", "64") => FeModulation::QAM_64,
+            ("QAM", "128") => FeModulation::QAM_128,
+            ("QAM", "256") => FeModulation::QAM_256,
+            ("QAMAUTO", "") => FeModulation::QAM_AUTO,
+            ("VSB", "8") => FeModulation::VSB_8,
+            ("VSB", "16") => FeModulation::VSB_16,
+            ("PSK", "8") => FeModulation::PSK_8,
+            ("APSK", "16") => FeModulation::APSK_16,
+            ("APSK", "32") => FeModulation::APSK_32,
+            ("DQPSK", "") => FeModulation::DQPSK,
+            ("QAMNR", "4") => FeModulation::QAM_4_NR,
+            ("QAM", "1024") => FeModulation::QAM_1024,
+            ("QAM", "4096") => FeModulation::QAM_4096,
+            ("APSKL", "8") => FeModulation::APSK_8_L,
+            ("APSKL", "16") => FeModulation::APSK_16_L,
+            ("APSKL", "32") => FeModulation::APSK_32_L,
+            ("APSK", "64") => FeModulation::APSK_64,
+            ("APSKL", "64") => FeModulation::APSK_64_L,
+            _ => {
+                return Err(UnknownNameError {
+                    kind: "modulation",
+                    name: s.to_string(),
+                });
+            }
+        })
+    }
 }
 
 /// Type of inversion band
@@ -237,148 +933,226 @@ pub enum FeModulation {
 ///
 /// (taken from [linux/dvb/frontend.h](https://github.com/gjasny/v4l-utils/blob/c4cb1d1bb6960679e1272493102c6dcf4cec76e7/include/linux/dvb/frontend.h#L248))
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum FeSpectralInversion {
     /// Don't do spectral band inversion.
-    INVERSION_OFF,
+    INVERSION_OFF = 0,
     /// Do spectral band inversion.
-    INVERSION_ON,
+    INVERSION_ON = 1,
     /// Autodetect spectral band inversion.
-    INVERSION_AUTO,
+    INVERSION_AUTO = 2,
 }
 
 /// Guard interval
 ///
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_guard_interval))
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum FeGuardInterval {
     /// Guard interval 1/32
-    GUARD_INTERVAL_1_32,
+    GUARD_INTERVAL_1_32 = 0,
     /// Guard interval 1/16
-    GUARD_INTERVAL_1_16,
+    GUARD_INTERVAL_1_16 = 1,
     /// Guard interval 1/8
-    GUARD_INTERVAL_1_8,
+    GUARD_INTERVAL_1_8 = 2,
     /// Guard interval 1/4
-    GUARD_INTERVAL_1_4,
+    GUARD_INTERVAL_1_4 = 3,
     /// Autodetect the guard interval
-    GUARD_INTERVAL_AUTO,
+    GUARD_INTERVAL_AUTO = 4,
     /// Guard interval 1/128
-    GUARD_INTERVAL_1_128,
+    GUARD_INTERVAL_1_128 = 5,
     /// Guard interval 19/128
-    GUARD_INTERVAL_19_128,
+    GUARD_INTERVAL_19_128 = 6,
     /// Guard interval 19/256
-    GUARD_INTERVAL_19_256,
+    GUARD_INTERVAL_19_256 = 7,
     /// PN length 420 (1/4)
-    GUARD_INTERVAL_PN420,
+    GUARD_INTERVAL_PN420 = 8,
     /// PN length 595 (1/6)
-    GUARD_INTERVAL_PN595,
+    GUARD_INTERVAL_PN595 = 9,
     /// PN length 945 (1/9)
-    GUARD_INTERVAL_PN945,
+    GUARD_INTERVAL_PN945 = 10,
     /// Guard interval 1/64
-    GUARD_INTERVAL_1_64,
+    GUARD_INTERVAL_1_64 = 11,
 }
 
 /// Transmission mode
 ///
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_transmit_mode))
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum FeTransmitMode {
     /// Transmission mode 2K
-    TRANSMISSION_MODE_2K,
+    TRANSMISSION_MODE_2K = 0,
     /// Transmission mode 8K
-    TRANSMISSION_MODE_8K,
+    TRANSMISSION_MODE_8K = 1,
     /// Autodetect transmission mode. The hardware will try to find the correct FFT-size (if capable) to fill in the missing parameters.
-    TRANSMISSION_MODE_AUTO,
+    TRANSMISSION_MODE_AUTO = 2,
     /// Transmission mode 4K
-    TRANSMISSION_MODE_4K,
+    TRANSMISSION_MODE_4K = 3,
     /// Transmission mode 1K
-    TRANSMISSION_MODE_1K,
+    TRANSMISSION_MODE_1K = 4,
     /// Transmission mode 16K
-    TRANSMISSION_MODE_16K,
+    TRANSMISSION_MODE_16K = 5,
     /// Transmission mode 32K
-    TRANSMISSION_MODE_32K,
+    TRANSMISSION_MODE_32K = 6,
     /// Single Carrier (C=1) transmission mode (DTMB only)
-    TRANSMISSION_MODE_C1,
+    TRANSMISSION_MODE_C1 = 7,
     /// Multi Carrier (C=3780) transmission mode (DTMB only)
-    TRANSMISSION_MODE_C3780,
+    TRANSMISSION_MODE_C3780 = 8,
 }
 
 /// Type of Forward Error Correction (FEC)
 ///
 /// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_code_rate))
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum FeCodeRate {
     /// No Forward Error Correction Code
     FEC_NONE = 0,
     /// Forward Error Correction Code 1/2
-    FEC_1_2,
+    FEC_1_2 = 1,
     /// Forward Error Correction Code 2/3
-    FEC_2_3,
+    FEC_2_3 = 2,
     /// Forward Error Correction Code 3/4
-    FEC_3_4,
+    FEC_3_4 = 3,
     /// Forward Error Correction Code 4/5
-    FEC_4_5,
+    FEC_4_5 = 4,
     /// Forward Error Correction Code 5/6
-    FEC_5_6,
+    FEC_5_6 = 5,
     /// Forward Error Correction Code 6/7
-    FEC_6_7,
+    FEC_6_7 = 6,
     /// Forward Error Correction Code 7/8
-    FEC_7_8,
+    FEC_7_8 = 7,
     /// Forward Error Correction Code 8/9
-    FEC_8_9,
+    FEC_8_9 = 8,
     /// Autodetect Error Correction Code
-    FEC_AUTO,
+    FEC_AUTO = 9,
     /// Forward Error Correction Code 3/5
-    FEC_3_5,
+    FEC_3_5 = 10,
     /// Forward Error Correction Code 9/10
-    FEC_9_10,
+    FEC_9_10 = 11,
     /// Forward Error Correction Code 2/5
-    FEC_2_5,
+    FEC_2_5 = 12,
     /// Forward Error Correction Code 1/3
-    FEC_1_3,
+    FEC_1_3 = 13,
     /// Forward Error Correction Code 1/4
-    FEC_1_4,
+    FEC_1_4 = 14,
     /// Forward Error Correction Code 5/9
-    FEC_5_9,
+    FEC_5_9 = 15,
     /// Forward Error Correction Code 7/9
-    FEC_7_9,
+    FEC_7_9 = 16,
     /// Forward Error Correction Code 8/15
-    FEC_8_15,
+    FEC_8_15 = 17,
     /// Forward Error Correction Code 11/15
-    FEC_11_15,
+    FEC_11_15 = 18,
     /// Forward Error Correction Code 13/18
-    FEC_13_18,
+    FEC_13_18 = 19,
     /// Forward Error Correction Code 9/20
-    FEC_9_20,
+    FEC_9_20 = 20,
     /// Forward Error Correction Code 11/20
-    FEC_11_20,
+    FEC_11_20 = 21,
     /// Forward Error Correction Code 23/36
-    FEC_23_36,
+    FEC_23_36 = 22,
     /// Forward Error Correction Code 25/36
-    FEC_25_36,
+    FEC_25_36 = 23,
     /// Forward Error Correction Code 13/45
-    FEC_13_45,
+    FEC_13_45 = 24,
     /// Forward Error Correction Code 26/45
-    FEC_26_45,
+    FEC_26_45 = 25,
     /// Forward Error Correction Code 28/45
-    FEC_28_45,
+    FEC_28_45 = 26,
     /// Forward Error Correction Code 32/45
-    FEC_32_45,
+    FEC_32_45 = 27,
     /// Forward Error Correction Code 77/90
-    FEC_77_90,
+    FEC_77_90 = 28,
     /// Forward Error Correction Code 11/45
-    FEC_11_45,
+    FEC_11_45 = 29,
     /// Forward Error Correction Code 4/15
-    FEC_4_15,
+    FEC_4_15 = 30,
     /// Forward Error Correction Code 14/45
-    FEC_14_45,
+    FEC_14_45 = 31,
     /// Forward Error Correction Code 7/15
-    FEC_7_15,
+    FEC_7_15 = 32,
+}
+
+/// Type of hierarchy
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_hierarchy))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum FeHierarchy {
+    /// No hierarchy
+    HIERARCHY_NONE,
+    /// Hierarchy 1
+    HIERARCHY_1,
+    /// Hierarchy 2
+    HIERARCHY_2,
+    /// Hierarchy 4
+    HIERARCHY_4,
+    /// Autodetect hierarchy (if supported)
+    HIERARCHY_AUTO,
+}
+
+/// Type of pilot tone
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_pilot))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum FePilot {
+    /// Pilot tones enabled
+    PILOT_ON,
+    /// Pilot tones disabled
+    PILOT_OFF,
+    /// Autodetect pilot tones
+    PILOT_AUTO,
+}
+
+/// Rolloff factor
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_rolloff))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum FeRolloff {
+    /// Roloff factor: α=35%
+    ROLLOFF_35,
+    /// Roloff factor: α=20%
+    ROLLOFF_20,
+    /// Roloff factor: α=25%
+    ROLLOFF_25,
+    /// Auto-detect the roloff factor.
+    ROLLOFF_AUTO,
+    /// Rolloff factor: α=15%
+    ROLLOFF_15,
+    /// Rolloff factor: α=10%
+    ROLLOFF_10,
+    /// Rolloff factor: α=5%
+    ROLLOFF_5,
+}
+
+/// Interleaving
+///
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/frontend-header.html#c.fe_interleaving))
+#[repr(C)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum FeInterleaving {
+    /// No interleaving.
+    INTERLEAVING_NONE,
+    /// Auto-detect interleaving.
+    INTERLEAVING_AUTO,
+    /// Interleaving of 240 symbols.
+    INTERLEAVING_240,
+    /// Interleaving of 720 symbols.
+    INTERLEAVING_720,
 }
@@ -0,0 +1,79 @@
+//! Building DiSEqC messages, used to control satellite equipment (switches, motors...)
+//!
+//! A DiSEqC message is made of a framing byte, an address byte, a command byte and up to 3 bytes of data.
+
+pub mod positioner;
+
+use std::time::Duration;
+
+use crate::frontend::data::{DvbDiseqcMasterCmd, SecMiniCmd, SecToneMode, SecVoltage};
+
+/// Framing byte: command from master, no reply required, first transmission.
+pub const FRAMING_NO_REPLY: u8 = 0xE0;
+/// Address byte: any LNB, switcher or SMATV.
+pub const ADDRESS_ANY_LNB_SWITCH: u8 = 0x10;
+/// Command byte: write to port group 0 (committed switches).
+pub const COMMAND_WRITE_N0: u8 = 0x38;
+
+/// Time for the voltage or the continuous tone to settle, and quiet time after a tone burst.
+pub const SETTLE_DELAY: Duration = Duration::from_millis(15);
+/// Time to wait after sending a message: about 54ms to transmit it, as the ioctl may return before, plus some quiet time.
+pub const MESSAGE_DELAY: Duration = Duration::from_millis(54);
+
+/// One step of a DiSEqC sequence, see [Frontend::run_diseqc_sequence](crate::frontend::wrapper::Frontend::run_diseqc_sequence).
+#[derive(Debug, Copy, Clone)]
+pub enum DiseqcStep {
+    Voltage(SecVoltage),
+    Tone(SecToneMode),
+    MasterCmd(DvbDiseqcMasterCmd),
+    Burst(SecMiniCmd),
+    /// Waits for given duration, instead of the standard delay of the previous step.
+    Delay(Duration),
+}
+
+impl DiseqcStep {
+    /// Standard time to wait after this step, before the next one.
+    pub fn standard_delay(&self) -> Duration {
+        match self {
+            DiseqcStep::MasterCmd(_) => MESSAGE_DELAY,
+            DiseqcStep::Voltage(_) | DiseqcStep::Tone(_) | DiseqcStep::Burst(_) => SETTLE_DELAY,
+            DiseqcStep::Delay(_) => Duration::ZERO,
+        }
+    }
+}
+
+/// Everything needed to select a port on a DiSEqC 1.0 committed switch.
+///
+/// The switch is driven with the following sequence (standard DiSEqC timings):
+/// 1. Disable the continuous tone, as DiSEqC messages are modulated on it,
+/// 2. Set [voltage](Self::voltage), then wait at least 15ms,
+/// 3. Send [cmd](Self::cmd), then wait at least 15ms (a message takes about 54ms to transmit, the ioctl may return before),
+/// 4. Set [tone](Self::tone).
+#[derive(Debug, Copy, Clone)]
+pub struct CommittedSwitch {
+    pub cmd: DvbDiseqcMasterCmd,
+    pub voltage: SecVoltage,
+    pub tone: SecToneMode,
+}
+
+/// Selects a port (0 to 3) on a committed switch, also passing the polarization and band to the LNB behind it.
+///
+/// Panics if `port` is greater than 3.
+pub fn committed_switch(port: u8, voltage: SecVoltage, tone: SecToneMode) -> CommittedSwitch {
+    assert!(port < 4, "committed switches only have ports 0 to 3");
+
+    let horizontal = matches!(voltage, SecVoltage::SEC_VOLTAGE_18);
+    let high_band = matches!(tone, SecToneMode::SEC_TONE_ON);
+    let bits = (port << 2) | ((horizontal as u8) << 1) | (high_band as u8);
+
+    CommittedSwitch {
+        cmd: DvbDiseqcMasterCmd::new(&[
+            FRAMING_NO_REPLY,
+            ADDRESS_ANY_LNB_SWITCH,
+            COMMAND_WRITE_N0,
+            0xF0 | bits,
+        ]),
+        voltage,
+        tone,
+    }
+}
@@ -0,0 +1,133 @@
+//! DiSEqC 1.2 commands for motorized dishes (positioners)
+
+use crate::frontend::{data::DvbDiseqcMasterCmd, diseqc::FRAMING_NO_REPLY};
+
+/// Address byte: polar/azimuth positioner.
+pub const ADDRESS_POSITIONER: u8 = 0x31;
+
+const COMMAND_HALT: u8 = 0x60;
+const COMMAND_DRIVE_EAST: u8 = 0x68;
+const COMMAND_DRIVE_WEST: u8 = 0x69;
+const COMMAND_STORE_POSITION: u8 = 0x6A;
+const COMMAND_GOTO_POSITION: u8 = 0x6B;
+const COMMAND_GOTO_X: u8 = 0x6E;
+
+/// Mean equatorial radius of the Earth, in km.
+const EARTH_RADIUS: f64 = 6378.14;
+/// Radius of the geostationary orbit, in km.
+const GEOSTATIONARY_RADIUS: f64 = 42164.57;
+
+/// How far a drive command should move the dish.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Drive {
+    /// Keep moving until [halt] is sent or a limit is reached.
+    Continuous,
+    /// Move for given number of seconds (1 to 127).
+    Timeout(u8),
+    /// Move by given number of steps (1 to 128).
+    Steps(u8),
+}
+
+impl Drive {
+    fn byte(&self) -> u8 {
+        match *self {
+            Drive::Continuous => 0x00,
+            Drive::Timeout(seconds) => {
+                assert!(
+                    (1..=0x7F).contains(&seconds),
+                    "drive timeout must be 1 to 127 seconds"
+                );
+                seconds
+            }
+            Drive::Steps(steps) => {
+                assert!((1..=0x80).contains(&steps), "drive steps must be 1 to 128");
+                // Steps are encoded as a negative number
+                steps.wrapping_neg()
+            }
+        }
+    }
+}
+
+/// Stops the motor.
+pub fn halt() -> DvbDiseqcMasterCmd {
+    DvbDiseqcMasterCmd::new(&[FRAMING_NO_REPLY, ADDRESS_POSITIONER, COMMAND_HALT])
+}
+
+/// Moves the dish towards the east.
+///
+/// Panics if the timeout or steps are out of range.
+pub fn drive_east(drive: Drive) -> DvbDiseqcMasterCmd {
+    DvbDiseqcMasterCmd::new(&[
+        FRAMING_NO_REPLY,
+        ADDRESS_POSITIONER,
+        COMMAND_DRIVE_EAST,
+        drive.byte(),
+    ])
+}
+
+/// Moves the dish towards the west.
+///
+/// Panics if the timeout or steps are out of range.
+pub fn drive_west(drive: Drive) -> DvbDiseqcMasterCmd {
+    DvbDiseqcMasterCmd::new(&[
+        FRAMING_NO_REPLY,
+        ADDRESS_POSITIONER,
+        COMMAND_DRIVE_WEST,
+        drive.byte(),
+    ])
+}
+
+/// Stores the current position of the dish in the motor memory, under given number.
+pub fn store_position(n: u8) -> DvbDiseqcMasterCmd {
+    DvbDiseqcMasterCmd::new(&[
+        FRAMING_NO_REPLY,
+        ADDRESS_POSITIONER,
+        COMMAND_STORE_POSITION,
+        n,
+    ])
+}
+
+/// Moves the dish to a position previously stored with [store_position].
+///
+/// Position 0 is the reference (center) position.
+pub fn goto_position(n: u8) -> DvbDiseqcMasterCmd {
+    DvbDiseqcMasterCmd::new(&[
+        FRAMING_NO_REPLY,
+        ADDRESS_POSITIONER,
+        COMMAND_GOTO_POSITION,
+        n,
+    ])
+}
+
+/// Rotation angle of a USALS motor to point at a satellite, in degrees. Positive towards the east.
+///
+/// Latitudes are positive towards the north, longitudes positive towards the east.
+/// This is the hour angle of the satellite as seen from the site, which is what a polar mount rotates along.
+pub fn usals_angle(site_lat: f64, site_long: f64, sat_long: f64) -> f64 {
+    let delta = (sat_long - site_long).to_radians();
+    let lat = site_lat.to_radians();
+
+    (GEOSTATIONARY_RADIUS * delta.sin())
+        .atan2(GEOSTATIONARY_RADIUS * delta.cos() - EARTH_RADIUS * lat.cos())
+        .to_degrees()
+}
+
+/// Moves the dish to point at the satellite at given longitude (USALS, also known as GotoX).
+///
+/// The motor computes nothing itself, the rotation angle is computed from the site coordinates with [usals_angle].
+/// It is sent with a 1/16th of a degree precision.
+pub fn usals_goto(site_lat: f64, site_long: f64, sat_long: f64) -> DvbDiseqcMasterCmd {
+    let angle = usals_angle(site_lat, site_long, sat_long);
+
+    let direction = if angle < 0.0 { 0xD0 } else { 0xE0 };
+    // Integer part of the angle on 8 bits, then fractional part in 1/16th of degrees on 4 bits
+    let sixteenths = (angle.abs() * 16.0).round() as u16 & 0x0FFF;
+
+    DvbDiseqcMasterCmd::new(&[
+        FRAMING_NO_REPLY,
+        ADDRESS_POSITIONER,
+        COMMAND_GOTO_X,
+        direction | (sixteenths >> 8) as u8,
+        sixteenths as u8,
+    ])
+}
@@ -1,5 +1,5 @@
 use std::{
-    ffi::c_uint,
+    ffi::{c_int, c_uint},
     mem::MaybeUninit,
     os::fd::{AsRawFd as _, BorrowedFd},
 };
@@ -7,22 +7,31 @@ use std::{
 use nix::errno::Errno;
 
 use crate::{
-    error::PropertyError,
+    error::{FrontendInfoError, PropertyError},
     frontend::{
-        data::{DTV_IOCTL_MAX_MSGS, DvbFrontendInfo},
-        ioctl::{fe_get_info, fe_get_property, fe_read_status, fe_set_property},
+        data::{
+            DTV_IOCTL_MAX_MSGS, DvbDiseqcMasterCmd, DvbFrontendEvent, DvbFrontendInfo,
+            DvbFrontendParameters, SecMiniCmd, SecToneMode, SecVoltage,
+        },
+        ioctl::{
+            fe_diseqc_send_burst, fe_diseqc_send_master_cmd, fe_get_event, fe_get_frontend,
+            fe_get_info, fe_get_property, fe_read_status, fe_set_frontend,
+            fe_set_frontend_tune_mode, fe_set_property, fe_set_tone, fe_set_voltage,
+        },
         property::{DtvProperties, DtvProperty},
     },
 };
 
-pub fn get_info(fd: BorrowedFd) -> Result<DvbFrontendInfo, Errno> {
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn get_info(fd: BorrowedFd) -> Result<DvbFrontendInfo, FrontendInfoError> {
     let mut info = MaybeUninit::uninit();
-    unsafe { fe_get_info(fd.as_raw_fd(), info.as_mut_ptr()) }?;
+    unsafe { fe_get_info(fd.as_raw_fd(), info.as_mut_ptr()) }.map_err(FrontendInfoError::from)?;
     // SAFETY: If fe_get_info did not throw an error, memory should now be initialized.
     let info = unsafe { info.assume_init() };
     Ok(info)
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
 pub fn read_status(fd: BorrowedFd) -> Result<c_uint, Errno> {
     let mut status = MaybeUninit::uninit();
     unsafe { fe_read_status(fd.as_raw_fd(), status.as_mut_ptr()) }?;
@@ -31,32 +40,155 @@ pub fn read_status(fd: BorrowedFd) -> Result<c_uint, Errno> {
     Ok(status)
 }
 
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/fe-get-event.html#description))
+///
+/// Retrieves the next frontend event, if any. If the frontend was opened in non-blocking mode and there is no event pending,
+/// `EWOULDBLOCK` is returned. `EOVERFLOW` means that events were lost because they were not read fast enough.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn get_event(fd: BorrowedFd) -> Result<DvbFrontendEvent, Errno> {
+    let mut event = MaybeUninit::uninit();
+    // SAFETY: FD is always valid, and the kernel fills the whole event on success. There should be no conditions or unhandled side-effects.
+    unsafe { fe_get_event(fd.as_raw_fd(), event.as_mut_ptr()) }?;
+    // SAFETY: If fe_get_event did not throw an error, memory should now be initialized.
+    let event = unsafe { event.assume_init() };
+    Ok(event)
+}
+
+/// Legacy: starts a tuning operation using specified parameters. Only use it for drivers predating the property API,
+/// [get_set_properties_raw] with ``DTV_TUNE`` should be preferred.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(params), ret, err)
+)]
+pub fn set_frontend(fd: BorrowedFd, params: &DvbFrontendParameters) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, DvbFrontendParameters is C-compatible and fully initialized. There should be no conditions or unhandled side-effects.
+    unsafe { fe_set_frontend(fd.as_raw_fd(), params) }?;
+    Ok(())
+}
+
+/// Legacy: queries the parameters currently used by the frontend. Which field of the union is valid depends on the frontend type.
+/// Prefer reading properties with [get_set_properties_raw].
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, err)
+)]
+pub fn get_frontend(fd: BorrowedFd) -> Result<DvbFrontendParameters, Errno> {
+    let mut params = MaybeUninit::uninit();
+    // SAFETY: FD is always valid, and the kernel fills the whole structure on success. There should be no conditions or unhandled side-effects.
+    unsafe { fe_get_frontend(fd.as_raw_fd(), params.as_mut_ptr()) }?;
+    // SAFETY: If fe_get_frontend did not throw an error, memory should now be initialized.
+    let params = unsafe { params.assume_init() };
+    Ok(params)
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/fe-diseqc-send-master-cmd.html#description))
+///
+/// Sends the DiSEqC command pointed by ``cmd`` to the antenna subsystem.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn diseqc_send_master_cmd(fd: BorrowedFd, cmd: &DvbDiseqcMasterCmd) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, DvbDiseqcMasterCmd is C-compatible and always valid. There should be no conditions or unhandled side-effects.
+    unsafe { fe_diseqc_send_master_cmd(fd.as_raw_fd(), cmd) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/fe-diseqc-send-burst.html#description))
+///
+/// This ioctl is used to set the generation of a 22kHz tone burst for mini DiSEqC satellite selection for 2x1 switches.
+/// This call requires read/write permissions.
+/// It provides support for what's specified at Digital Satellite Equipment Control (DiSEqC) - Simple "ToneBurst" Detection Circuit specification.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn diseqc_send_burst(fd: BorrowedFd, burst: SecMiniCmd) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, and the burst is passed by value. There should be no conditions or unhandled side-effects.
+    unsafe { fe_diseqc_send_burst(fd.as_raw_fd(), burst as c_int) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/fe-set-tone.html#description))
+///
+/// This ioctl is used to set the generation of the continuous 22kHz tone.
+/// This call requires read/write permissions.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn set_tone(fd: BorrowedFd, tone: SecToneMode) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, and the tone is passed by value. There should be no conditions or unhandled side-effects.
+    unsafe { fe_set_tone(fd.as_raw_fd(), tone as c_int) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/fe-set-voltage.html#description))
+///
+/// This ioctl allows to set the DC voltage level sent through the antenna cable to 13V, 18V or off.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn set_voltage(fd: BorrowedFd, voltage: SecVoltage) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, and the voltage is passed by value. There should be no conditions or unhandled side-effects.
+    unsafe { fe_set_voltage(fd.as_raw_fd(), voltage as c_int) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/fe-set-frontend-tune-mode.html#description))
+///
+/// Allow setting tuner mode flags to the frontend, between 0 (normal) or FE_TUNE_MODE_ONESHOT mode
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret, err))]
+pub fn set_frontend_tune_mode(fd: BorrowedFd, flags: u32) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, and flags are passed by value. There should be no conditions or unhandled side-effects.
+    unsafe { fe_set_frontend_tune_mode(fd.as_raw_fd(), flags as c_int) }?;
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(properties), err)
+)]
 pub fn get_set_properties_raw(
     fd: BorrowedFd,
     set: bool,
-    count: usize,
-    ptr: *mut DtvProperty,
+    properties: &mut [DtvProperty],
 ) -> Result<(), PropertyError> {
+    let count = properties.len();
     if count == 0 {
         return Ok(());
     }
 
     if count > DTV_IOCTL_MAX_MSGS {
-        return Err(PropertyError::TooManyParameters);
+        return Err(PropertyError::TooManyParameters {
+            requested: count,
+            max: DTV_IOCTL_MAX_MSGS,
+        });
     }
 
-    let mut properties = DtvProperties {
+    let mut raw = DtvProperties {
         num: count as u32,
-        props: ptr,
+        props: properties.as_mut_ptr(),
     };
 
     if set {
-        unsafe { fe_set_property(fd.as_raw_fd(), &mut properties as *mut DtvProperties) }
+        #[cfg(feature = "tracing")]
+        trace_properties(properties, "setting property");
+        // SAFETY: FD is always valid, and the pointer and count come from a live slice the kernel stays within.
+        unsafe { fe_set_property(fd.as_raw_fd(), &mut raw as *mut DtvProperties) }
             .map_err(PropertyError::SetProperty)?;
     } else {
-        unsafe { fe_get_property(fd.as_raw_fd(), &mut properties as *mut DtvProperties) }
+        // SAFETY: FD is always valid, and the pointer and count come from a live slice the kernel stays within.
+        unsafe { fe_get_property(fd.as_raw_fd(), &mut raw as *mut DtvProperties) }
             .map_err(PropertyError::GetProperty)?;
+        #[cfg(feature = "tracing")]
+        trace_properties(properties, "got property");
     }
 
     Ok(())
 }
+
+/// Logs the command, data and result of each property.
+#[cfg(feature = "tracing")]
+fn trace_properties(properties: &[DtvProperty], message: &str) {
+    use crate::frontend::property::Command;
+
+    for property in properties {
+        let (cmd, result) = (property.cmd, property.result);
+        // SAFETY: The first 4 bytes are initialized by every variant of the union, and any value is a valid u32.
+        let data = unsafe { property.u.data };
+        match Command::try_from(cmd) {
+            Ok(cmd) => tracing::debug!(?cmd, data, result, "{message}"),
+            Err(()) => tracing::debug!(cmd, data, result, "{message}"),
+        }
+    }
+}
@@ -1,20 +1,71 @@
 use std::ffi::c_uint;
 
-use nix::{ioctl_read, ioctl_write_ptr};
+use nix::{ioctl_read, ioctl_write_int_bad, ioctl_write_ptr, request_code_none};
 
 use crate::{
     IOCTL_TYPE,
-    frontend::{data::DvbFrontendInfo, property::DtvProperties},
+    frontend::{
+        data::{DvbDiseqcMasterCmd, DvbFrontendEvent, DvbFrontendInfo, DvbFrontendParameters},
+        property::DtvProperties,
+    },
 };
 
 pub const FE_GET_INFO: u8 = 61;
 ioctl_read!(fe_get_info, IOCTL_TYPE, FE_GET_INFO, DvbFrontendInfo);
 
+pub const FE_DISEQC_SEND_MASTER_CMD: u8 = 63;
+ioctl_write_ptr!(
+    fe_diseqc_send_master_cmd,
+    IOCTL_TYPE,
+    FE_DISEQC_SEND_MASTER_CMD,
+    DvbDiseqcMasterCmd
+);
+
+pub const FE_DISEQC_SEND_BURST: u8 = 65;
+ioctl_write_int_bad!(
+    fe_diseqc_send_burst,
+    request_code_none!(IOCTL_TYPE, FE_DISEQC_SEND_BURST)
+); // Takes a SecMiniCmd by value
+
+pub const FE_SET_TONE: u8 = 66;
+ioctl_write_int_bad!(fe_set_tone, request_code_none!(IOCTL_TYPE, FE_SET_TONE)); // Takes a SecToneMode by value
+
+pub const FE_SET_VOLTAGE: u8 = 67;
+ioctl_write_int_bad!(
+    fe_set_voltage,
+    request_code_none!(IOCTL_TYPE, FE_SET_VOLTAGE)
+); // Takes a SecVoltage by value
+
 pub const FE_READ_STATUS: u8 = 69;
 ioctl_read!(fe_read_status, IOCTL_TYPE, FE_READ_STATUS, c_uint); // Maps to FeStatus struct for bits
 
+pub const FE_SET_FRONTEND: u8 = 76;
+ioctl_write_ptr!(
+    fe_set_frontend,
+    IOCTL_TYPE,
+    FE_SET_FRONTEND,
+    DvbFrontendParameters
+); // Legacy, superseded by FE_SET_PROPERTY
+
+pub const FE_GET_FRONTEND: u8 = 77;
+ioctl_read!(
+    fe_get_frontend,
+    IOCTL_TYPE,
+    FE_GET_FRONTEND,
+    DvbFrontendParameters
+); // Legacy, superseded by FE_GET_PROPERTY
+
+pub const FE_GET_EVENT: u8 = 78;
+ioctl_read!(fe_get_event, IOCTL_TYPE, FE_GET_EVENT, DvbFrontendEvent);
+
 pub const FE_SET_PROPERTY: u8 = 82;
 ioctl_write_ptr!(fe_set_property, IOCTL_TYPE, FE_SET_PROPERTY, DtvProperties);
 
 pub const FE_GET_PROPERTY: u8 = 83;
 ioctl_read!(fe_get_property, IOCTL_TYPE, FE_GET_PROPERTY, DtvProperties);
+
+pub const FE_SET_FRONTEND_TUNE_MODE: u8 = 81;
+ioctl_write_int_bad!(
+    fe_set_frontend_tune_mode,
+    request_code_none!(IOCTL_TYPE, FE_SET_FRONTEND_TUNE_MODE)
+); // Takes flags by value
@@ -0,0 +1,90 @@
+//! Low-Noise Block downconverters, used to receive satellite signals
+
+use crate::frontend::data::{SecToneMode, SecVoltage};
+
+/// Frequency band of an LNB with two local oscillators.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Band {
+    Low,
+    High,
+}
+
+impl Band {
+    /// Tone to send to the LNB to select this band.
+    pub fn tone(&self) -> SecToneMode {
+        match self {
+            Band::Low => SecToneMode::SEC_TONE_OFF,
+            Band::High => SecToneMode::SEC_TONE_ON,
+        }
+    }
+}
+
+/// Polarization of a satellite transponder.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Polarization {
+    Horizontal,
+    Vertical,
+    CircularLeft,
+    CircularRight,
+}
+
+impl Polarization {
+    /// Voltage to feed the LNB with to receive this polarization.
+    pub fn voltage(&self) -> SecVoltage {
+        match self {
+            Polarization::Vertical | Polarization::CircularRight => SecVoltage::SEC_VOLTAGE_13,
+            Polarization::Horizontal | Polarization::CircularLeft => SecVoltage::SEC_VOLTAGE_18,
+        }
+    }
+}
+
+/// Description of an LNB, all frequencies in kHz.
+///
+/// An LNB converts the transponder frequency down to an Intermediate Frequency (IF) using a local oscillator (LOF).
+/// Universal LNBs have two local oscillators, the high one being selected with the 22kHz tone
+/// for transponders above the switch frequency.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Lnb {
+    /// Frequency of the low band (or only) local oscillator.
+    pub low_lof: u32,
+    /// Frequency of the high band local oscillator, if any.
+    pub high_lof: Option<u32>,
+    /// Transponder frequency from which the high band is used.
+    pub switch_frequency: Option<u32>,
+}
+
+impl Lnb {
+    /// Universal Ku-band LNB: 9750 MHz low band, 10600 MHz high band, switching at 11700 MHz.
+    pub const UNIVERSAL: Lnb = Lnb {
+        low_lof: 9_750_000,
+        high_lof: Some(10_600_000),
+        switch_frequency: Some(11_700_000),
+    };
+
+    /// Single band Ku-band LNB with a 9750 MHz oscillator.
+    pub const SINGLE: Lnb = Lnb::single(9_750_000);
+
+    /// C-band LNB with a 5150 MHz oscillator, above the transponder frequencies.
+    pub const C_BAND: Lnb = Lnb::single(5_150_000);
+
+    /// LNB with a single local oscillator, at given frequency in kHz.
+    pub const fn single(lof: u32) -> Lnb {
+        Lnb {
+            low_lof: lof,
+            high_lof: None,
+            switch_frequency: None,
+        }
+    }
+
+    /// Computes the Intermediate Frequency to tune to for given transponder frequency, and the band to select.
+    ///
+    /// LNBs with the oscillator above the transponder frequency (such as C-band) are handled as well.
+    pub fn downconvert(&self, transponder_khz: u32) -> (u32, Band) {
+        match (self.high_lof, self.switch_frequency) {
+            (Some(high_lof), Some(switch)) if transponder_khz >= switch => {
+                (transponder_khz.abs_diff(high_lof), Band::High)
+            }
+            _ => (transponder_khz.abs_diff(self.low_lof), Band::Low),
+        }
+    }
+}
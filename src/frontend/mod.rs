@@ -1,5 +1,14 @@
 pub mod data;
+#[cfg(feature = "std")]
+pub mod diseqc;
+#[cfg(feature = "std")]
 pub mod functions;
+#[cfg(feature = "std")]
 pub mod ioctl;
+#[cfg(feature = "std")]
+pub mod lnb;
 pub mod property;
+#[cfg(feature = "std")]
 pub mod queries;
+#[cfg(feature = "std")]
+pub mod wrapper;
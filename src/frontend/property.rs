@@ -1,4 +1,8 @@
-use std::ffi::{c_int, c_void};
+use core::{
+    ffi::{c_int, c_void},
+    fmt,
+    mem::MaybeUninit,
+};
 
 use enum_from_discriminant_derive::TryFromDiscriminant;
 
@@ -7,7 +11,7 @@ use enum_from_discriminant_derive::TryFromDiscriminant;
 
 // Specifically setting this enum to u32 as it is just a collection of defines in header file, and will only be used in cmd field in DtvProperty.
 #[repr(u32)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, TryFromDiscriminant)]
 #[allow(non_camel_case_types)]
 pub enum Command {
     DTV_UNDEFINED = 0,
@@ -229,6 +233,22 @@ pub enum Command {
     DTV_SCRAMBLING_SEQUENCE_INDEX = 70,
 }
 
+impl Command {
+    /// Whether this command reads statistics (``DTV_STAT_*``), which use the [DtvFeStats] variant of the union.
+    pub fn is_stat(&self) -> bool {
+        (Command::DTV_STAT_SIGNAL_STRENGTH as u32..=Command::DTV_STAT_TOTAL_BLOCK_COUNT as u32)
+            .contains(&(*self as u32))
+    }
+
+    /// Whether this command carries bytes, which use the [DtvPropertyABuffer] variant of the union.
+    pub fn is_buffer(&self) -> bool {
+        matches!(
+            self,
+            Command::DTV_DISEQC_MASTER | Command::DTV_DISEQC_SLAVE_REPLY | Command::DTV_ENUM_DELSYS
+        )
+    }
+}
+
 //
 // ----- Structs
 
@@ -250,25 +270,109 @@ pub struct DtvProperty {
 
 impl DtvProperty {
     pub fn new_empty(cmd: Command) -> DtvProperty {
-        // TODO: Is setting DtvPropertyUnion data to 0 enough ?
+        DtvProperty::new_data(cmd, 0)
+    }
+
+    /// Creates a property used to read statistics (``DTV_STAT_*``).
+    ///
+    /// The whole union is zeroed, so that ``u.st`` reads as an empty set of statistics until the kernel fills it.
+    pub fn new_stats(cmd: Command) -> DtvProperty {
         DtvProperty {
             cmd: cmd as u32,
             reserved: [0; 3],
-            u: DtvPropertyUnion { data: 0 },
+            u: zeroed_union(),
             result: 0,
         }
     }
 
+    /// Creates a property carrying a number in ``u.data``. The rest of the union is zeroed.
     pub fn new_data(cmd: Command, data: u32) -> DtvProperty {
+        let mut u = zeroed_union();
+        u.data = data;
+        DtvProperty {
+            cmd: cmd as u32,
+            reserved: [0; 3],
+            u,
+            result: 0,
+        }
+    }
+
+    /// Creates a property carrying bytes in the ``u.buffer`` variant, such as ``DTV_DISEQC_MASTER``.
+    ///
+    /// Panics if there are more than [DTV_BUFFER_MAX_LEN] bytes.
+    pub fn new_buffer(cmd: Command, bytes: &[u8]) -> DtvProperty {
+        assert!(
+            bytes.len() <= DTV_BUFFER_MAX_LEN,
+            "property buffers are limited to {DTV_BUFFER_MAX_LEN} bytes"
+        );
+        let mut data = [0; DTV_BUFFER_MAX_LEN];
+        data[..bytes.len()].copy_from_slice(bytes);
+        let mut u = zeroed_union();
+        u.buffer = DtvPropertyABuffer {
+            data,
+            len: bytes.len() as u32,
+            reserved1: [0; 3],
+            reserved2: core::ptr::null_mut(),
+        };
         DtvProperty {
             cmd: cmd as u32,
             reserved: [0; 3],
-            u: DtvPropertyUnion { data },
+            u,
             result: 0,
         }
     }
 }
 
+/// Every byte of the union is initialized, whatever variant is read later.
+fn zeroed_union() -> DtvPropertyUnion {
+    // SAFETY: All variants of the union only contain integers, arrays of integers and a raw pointer, for which all zeroes is valid.
+    unsafe { MaybeUninit::<DtvPropertyUnion>::zeroed().assume_init() }
+}
+
+/// Shows the command by name, and the union as the variant used by that command:
+/// a number for most commands, raw bytes for statistics and buffers.
+///
+/// Properties created with the constructors of this type, or filled by the kernel, can always be shown.
+/// A property written by hand must initialize the variant its command uses.
+impl fmt::Debug for DtvProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Fields are copied out, as references into a packed struct are not allowed
+        let raw_cmd = self.cmd;
+        let result = self.result;
+        let u = self.u;
+
+        let mut debug = f.debug_struct("DtvProperty");
+        match Command::try_from(raw_cmd) {
+            Ok(cmd) => {
+                debug.field("cmd", &cmd);
+                if cmd.is_stat() {
+                    // SAFETY: The constructors zero the whole union, and the kernel writes the whole st variant for statistics commands.
+                    // The variant is packed, so all of its bytes belong to fields and none are padding.
+                    let bytes: [u8; size_of::<DtvFeStats>()] =
+                        unsafe { core::mem::transmute(u.st) };
+                    debug.field("st", &bytes);
+                } else if cmd.is_buffer() {
+                    // SAFETY: The constructors zero the whole union, and new_buffer and the kernel write the buffer variant for buffer commands.
+                    // Any value is valid for its bytes and length.
+                    let buffer = unsafe { u.buffer };
+                    let len = (buffer.len as usize).min(DTV_BUFFER_MAX_LEN);
+                    debug.field("buffer", &&buffer.data[..len]);
+                } else {
+                    // SAFETY: The first 4 bytes are initialized by every variant, and any value is valid for a u32.
+                    debug.field("data", &unsafe { u.data });
+                }
+            }
+            Err(()) => {
+                debug.field("cmd", &raw_cmd);
+            }
+        }
+        debug.field("result", &result).finish()
+    }
+}
+
+/// Size of the data array of [DtvPropertyABuffer].
+pub const DTV_BUFFER_MAX_LEN: usize = 32;
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union DtvPropertyUnion {
@@ -301,7 +405,7 @@ pub union DtvStatsValue {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DtvPropertyABuffer {
-    pub data: [u8; 32],
+    pub data: [u8; DTV_BUFFER_MAX_LEN],
     pub len: u32,
     pub reserved1: [u32; 3],
     pub reserved2: *mut c_void,
@@ -1,10 +1,17 @@
-use std::{collections::BTreeSet, marker::PhantomData};
+use std::{
+    collections::{BTreeSet, btree_set},
+    marker::PhantomData,
+};
 
 use crate::{
     error::DtvError,
     frontend::{
-        data::{FeDeliverySystem, FeModulation},
+        data::{
+            FeCodeRate, FeDeliverySystem, FeHierarchy, FeInterleaving, FeModulation, FePilot,
+            FeRolloff, SecToneMode, SecVoltage,
+        },
         property::{Command, DtvProperty, DtvPropertyUnion, DtvStatsValue, FeCapScaleParams},
+        queries::set::BandwidthHz,
     },
 };
 
@@ -51,7 +58,7 @@ impl<T: PropertyQuery> PendingQuery<T> {
         Ok(T::from_property(property.u))
     }
 
-    pub fn desc(&mut self) -> QueryDescription {
+    pub fn desc(&mut self) -> QueryDescription<'_> {
         QueryDescription {
             command: T::associated_command(),
             property: &mut self.memory,
@@ -94,12 +101,101 @@ impl StatResult {
     }
 }
 
+/// Reads a stat property that is expected to hold values (decibel or relative).
+fn value_stats(u: DtvPropertyUnion) -> Vec<Option<ValueStat>> {
+    stat_results(u)
+        .into_iter()
+        .map(|res| match res? {
+            StatResult::Value(value_stat) => Some(value_stat),
+            StatResult::Count(_) => panic!("expected a value, not a count"),
+        })
+        .collect()
+}
+
+/// Reads a stat property that is expected to hold counts.
+fn count_stats(u: DtvPropertyUnion) -> Vec<Option<u64>> {
+    stat_results(u)
+        .into_iter()
+        .map(|res| match res? {
+            StatResult::Value(_) => panic!("expected a count, not a value"),
+            StatResult::Count(count) => Some(count),
+        })
+        .collect()
+}
+
+/// Reads all entries of a stat property.
+///
+/// Most frontends report a single, global measurement.
+/// Some delivery systems such as ISDB-T also report one measurement per layer, after the global one.
+fn stat_results(u: DtvPropertyUnion) -> Vec<Option<StatResult>> {
+    let stats = unsafe { u.st };
+    let len = (stats.len as usize).min(stats.stat.len());
+    stats.stat[..len]
+        .iter()
+        .map(|stat| {
+            let scale =
+                FeCapScaleParams::try_from(stat.scale).expect("unexpected value for stat type");
+            StatResult::from(scale, stat.value)
+        })
+        .collect()
+}
+
+/// Bytes held by a property using the buffer variant, up to its length.
+///
+/// The length comes from the kernel, so it is clamped to the size of the array rather than trusted.
+/// Must only be called on the result of a command the kernel answers with the buffer variant.
+pub(crate) fn buffer_bytes(u: &DtvPropertyUnion) -> &[u8] {
+    // SAFETY: Only called for commands that fill the buffer variant. Any value is valid for both the bytes and the length,
+    // and the union is not packed, so the reference is aligned.
+    let buffer = unsafe { &u.buffer };
+    let len = (buffer.len as usize).min(buffer.data.len());
+    &buffer.data[..len]
+}
+
+/// Kind of measurement held by a [ValueStat], to tell how a decibel value should be read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// [SignalStrength], decibels are dBm.
+    SignalStrength,
+    /// [CarrierSignalToNoise], decibels are dB.
+    CarrierSignalToNoise,
+}
+
+impl Metric {
+    /// Decibel values mapped to 0% and 100%, in 0.001 dB units.
+    ///
+    /// These are rough bounds of what tuners report in practice: below the low one nothing can be received,
+    /// above the high one the signal is as good as it gets.
+    fn decibel_range(self) -> (i64, i64) {
+        match self {
+            Metric::SignalStrength => (-85_000, -25_000),
+            Metric::CarrierSignalToNoise => (0, 30_000),
+        }
+    }
+}
+
+impl ValueStat {
+    /// Normalizes the value to 0-100, for a signal meter that does not depend on the scale the driver uses.
+    ///
+    /// Relative values are taken as they are, 65535 being 100%. Decibel values are placed linearly
+    /// between bounds that depend on the metric, and clamped.
+    pub fn as_percentage(&self, metric: Metric) -> f64 {
+        match *self {
+            ValueStat::Relative(value) => value.min(0xFFFF) as f64 / 65535.0 * 100.0,
+            ValueStat::Decibel(value) => {
+                let (low, high) = metric.decibel_range();
+                let clamped = value.clamp(low, high);
+                (clamped - low) as f64 / (high - low) as f64 * 100.0
+            }
+        }
+    }
+}
+
 impl PartialOrd for ValueStat {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match (self, other) {
-            (ValueStat::Decibel(_a), ValueStat::Decibel(_b)) => {
-                todo!("no idea how the dB info is encoded")
-            }
+            // Both are signed values in 0.001 dB units
+            (ValueStat::Decibel(a), ValueStat::Decibel(b)) => Some(a.cmp(b)),
             (ValueStat::Relative(a), ValueStat::Relative(b)) => Some(a.cmp(b)),
             _ => None,
         }
@@ -122,18 +218,113 @@ impl PropertyQuery for EnumerateDeliverySystems {
     }
 
     fn from_property(u: DtvPropertyUnion) -> Self {
-        let len = unsafe { u.buffer.len } as usize;
-
-        let mut systems = BTreeSet::new();
-        for i in 0..len {
-            let data = unsafe { u.buffer.data[i] };
-            systems.insert(FeDeliverySystem::try_from(data).unwrap());
-        }
+        let systems = buffer_bytes(&u)
+            .iter()
+            .map(|&data| FeDeliverySystem::try_from(data).unwrap())
+            .collect();
 
         EnumerateDeliverySystems(systems)
     }
 }
 
+impl EnumerateDeliverySystems {
+    pub fn contains(&self, system: FeDeliverySystem) -> bool {
+        self.0.contains(&system)
+    }
+
+    /// Iterates over the supported systems, oldest first.
+    pub fn iter(&self) -> btree_set::Iter<'_, FeDeliverySystem> {
+        self.0.iter()
+    }
+}
+
+/// Yields the supported systems, oldest first.
+impl IntoIterator for EnumerateDeliverySystems {
+    type Item = FeDeliverySystem;
+    type IntoIter = btree_set::IntoIter<FeDeliverySystem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EnumerateDeliverySystems {
+    type Item = &'a FeDeliverySystem;
+    type IntoIter = btree_set::Iter<'a, FeDeliverySystem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+// ---
+
+/// Number of capability descriptors of the frontend, see [Capability].
+#[derive(Debug)]
+pub struct CapabilityCount(pub u32);
+impl PropertyQuery for CapabilityCount {
+    fn associated_command() -> Command {
+        Command::DTV_FE_CAPABILITY_COUNT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        // SAFETY: No matter what data is provided, a u32 always has a valid value
+        CapabilityCount(unsafe { u.data })
+    }
+}
+
+// ---
+
+/// One capability descriptor of the frontend, beyond the fixed bits of `FE_GET_INFO`.
+///
+/// Entries are read one property each, with the index of the entry (below [CapabilityCount]) in the data field.
+/// A plain query reads entry 0, see [Frontend::enumerate_capabilities](crate::frontend::wrapper::Frontend::enumerate_capabilities)
+/// to read them all.
+#[derive(Debug)]
+pub struct Capability(pub u32);
+impl PropertyQuery for Capability {
+    fn associated_command() -> Command {
+        Command::DTV_FE_CAPABILITY
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        // SAFETY: No matter what data is provided, a u32 always has a valid value
+        Capability(unsafe { u.data })
+    }
+}
+
+// ---
+
+/// Delivery system the frontend is currently set to.
+#[derive(Debug)]
+pub struct ActiveDeliverySystem(pub FeDeliverySystem);
+impl PropertyQuery for ActiveDeliverySystem {
+    fn associated_command() -> Command {
+        Command::DTV_DELIVERY_SYSTEM
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe {
+            FeDeliverySystem::try_from(u.data).expect("unexpected value for delivery system")
+        })
+    }
+}
+
+// ---
+
+/// Reply of a DiSEqC slave (switch, positioner...) to the last message, if it sent one.
+#[derive(Debug)]
+pub struct DiseqcSlaveReply(pub Vec<u8>);
+impl PropertyQuery for DiseqcSlaveReply {
+    fn associated_command() -> Command {
+        Command::DTV_DISEQC_SLAVE_REPLY
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        DiseqcSlaveReply(buffer_bytes(&u).to_vec())
+    }
+}
+
 // ---
 
 #[derive(Debug)]
@@ -148,7 +339,21 @@ impl PropertyQuery for Frequency {
     }
 }
 
-// TODO: Return correct UOM when given system ?
+impl Frequency {
+    /// Converts to a proper frequency, given the delivery system the frontend was tuned with.
+    ///
+    /// Satellite systems use kHz while cable and terrestrial ones use Hz.
+    #[cfg(feature = "uom")]
+    pub fn as_hertz(&self, system: FeDeliverySystem) -> uom::si::f64::Frequency {
+        use uom::si::frequency::{hertz, kilohertz};
+
+        if system.is_satellite() {
+            uom::si::f64::Frequency::new::<kilohertz>(self.0.into())
+        } else {
+            uom::si::f64::Frequency::new::<hertz>(self.0.into())
+        }
+    }
+}
 
 // ---
 
@@ -168,6 +373,155 @@ impl PropertyQuery for Modulation {
 
 // ---
 
+/// Inner Forward Error Correction code rate.
+///
+/// When tuned with `FEC_AUTO`, drivers usually report the rate the demodulator actually locked with.
+#[derive(Debug)]
+pub struct InnerFec(pub FeCodeRate);
+impl PropertyQuery for InnerFec {
+    fn associated_command() -> Command {
+        Command::DTV_INNER_FEC
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe { FeCodeRate::try_from(u.data).expect("unexpected value for code rate") })
+    }
+}
+
+// ---
+
+/// Pilot tones setting, as resolved by the driver.
+#[derive(Debug)]
+pub struct Pilot(pub FePilot);
+impl PropertyQuery for Pilot {
+    fn associated_command() -> Command {
+        Command::DTV_PILOT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe { FePilot::try_from(u.data).expect("unexpected value for pilot") })
+    }
+}
+
+// ---
+
+/// Rolloff factor, as resolved by the driver.
+#[derive(Debug)]
+pub struct Rolloff(pub FeRolloff);
+impl PropertyQuery for Rolloff {
+    fn associated_command() -> Command {
+        Command::DTV_ROLLOFF
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe { FeRolloff::try_from(u.data).expect("unexpected value for rolloff") })
+    }
+}
+
+// ---
+
+/// DC voltage currently fed to the LNBf.
+#[derive(Debug)]
+pub struct Voltage(pub SecVoltage);
+impl PropertyQuery for Voltage {
+    fn associated_command() -> Command {
+        Command::DTV_VOLTAGE
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe { SecVoltage::try_from(u.data).expect("unexpected value for voltage") })
+    }
+}
+
+// ---
+
+/// Whether the 22kHz tone is currently sent to the antenna.
+#[derive(Debug)]
+pub struct Tone(pub SecToneMode);
+impl PropertyQuery for Tone {
+    fn associated_command() -> Command {
+        Command::DTV_TONE
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe { SecToneMode::try_from(u.data).expect("unexpected value for tone") })
+    }
+}
+
+// ---
+
+/// Hierarchy of a DVB-T channel, showing whether it is received as hierarchical.
+#[derive(Debug)]
+pub struct Hierarchy(pub FeHierarchy);
+impl PropertyQuery for Hierarchy {
+    fn associated_command() -> Command {
+        Command::DTV_HIERARCHY
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe { FeHierarchy::try_from(u.data).expect("unexpected value for hierarchy") })
+    }
+}
+
+// ---
+
+/// Interleaving of a DTMB channel.
+#[derive(Debug)]
+pub struct Interleaving(pub FeInterleaving);
+impl PropertyQuery for Interleaving {
+    fn associated_command() -> Command {
+        Command::DTV_INTERLEAVING
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(unsafe {
+            FeInterleaving::try_from(u.data).expect("unexpected value for interleaving")
+        })
+    }
+}
+
+// ---
+
+/// PLP being received, as reported by old DVB-T2 drivers that predate ``DTV_STREAM_ID``.
+///
+/// New code should prefer reading ``DTV_STREAM_ID``.
+#[derive(Debug)]
+pub struct Dvbt2PlpIdLegacy(pub u32);
+impl PropertyQuery for Dvbt2PlpIdLegacy {
+    fn associated_command() -> Command {
+        Command::DTV_DVBT2_PLP_ID_LEGACY
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        // SAFETY: No matter what data is provided, a u32 always has a valid value
+        Self(unsafe { u.data })
+    }
+}
+
+// ---
+
+/// Bandwidth of the channel, in Hz.
+#[derive(Debug)]
+pub struct Bandwidth(pub u32);
+impl PropertyQuery for Bandwidth {
+    fn associated_command() -> Command {
+        Command::DTV_BANDWIDTH_HZ
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        // SAFETY: No matter what data is provided, a u32 always has a valid value
+        Self(unsafe { u.data })
+    }
+}
+impl Bandwidth {
+    /// The bandwidth as one of the standard values, `None` if the driver reported something else.
+    pub fn standard(&self) -> Option<BandwidthHz> {
+        BandwidthHz::from_hz(self.0)
+    }
+}
+
+// ---
+
 pub struct SymbolRate(pub u32);
 impl PropertyQuery for SymbolRate {
     fn associated_command() -> Command {
@@ -180,34 +534,37 @@ impl PropertyQuery for SymbolRate {
     }
 }
 
+impl SymbolRate {
+    /// Converts to a rate, in symbols per second (Baud) for all delivery systems.
+    #[cfg(feature = "uom")]
+    pub fn as_hertz(&self) -> uom::si::f64::Frequency {
+        uom::si::f64::Frequency::new::<uom::si::frequency::hertz>(self.0.into())
+    }
+}
+
 // ---
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct SignalStrength(pub Option<ValueStat>);
+pub struct SignalStrength(pub Vec<Option<ValueStat>>);
 impl PropertyQuery for SignalStrength {
     fn associated_command() -> Command {
         Command::DTV_STAT_SIGNAL_STRENGTH
     }
 
     fn from_property(u: DtvPropertyUnion) -> Self {
-        let stats = unsafe { u.st };
-        assert_eq!(stats.len, 1);
-        let stat = stats.stat[0];
-        let scale = FeCapScaleParams::try_from(stat.scale).expect("unexpected value for stat type");
-        let res = match StatResult::from(scale, stat.value) {
-            Some(v) => v,
-            None => return Self(None),
-        };
-        match res {
-            StatResult::Value(value_stat) => Self(Some(value_stat)),
-            StatResult::Count(_) => panic!("expected a value, not a count"),
-        }
+        Self(value_stats(u))
+    }
+}
+impl SignalStrength {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<ValueStat> {
+        self.0.first().copied().flatten()
     }
 }
 
 impl PartialOrd for SignalStrength {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match (self.0, other.0) {
+        match (self.global(), other.global()) {
             (None, None) => None,
             (None, Some(_)) => Some(std::cmp::Ordering::Less),
             (Some(_), None) => Some(std::cmp::Ordering::Greater),
@@ -218,30 +575,164 @@ impl PartialOrd for SignalStrength {
 
 // --
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct CarrierSignalToNoise(pub Vec<Option<ValueStat>>);
+impl PropertyQuery for CarrierSignalToNoise {
+    fn associated_command() -> Command {
+        Command::DTV_STAT_CNR
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(value_stats(u))
+    }
+}
+impl CarrierSignalToNoise {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<ValueStat> {
+        self.0.first().copied().flatten()
+    }
+}
+
+// --
+
 #[derive(Debug)]
-pub struct CarrierSignalToNoise(pub Option<ValueStat>);
+pub struct PreErrorBitCount(pub Vec<Option<u64>>);
+impl PropertyQuery for PreErrorBitCount {
+    fn associated_command() -> Command {
+        Command::DTV_STAT_PRE_ERROR_BIT_COUNT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(count_stats(u))
+    }
+}
+impl PreErrorBitCount {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<u64> {
+        self.0.first().copied().flatten()
+    }
+}
+
+// --
+
+#[derive(Debug)]
+pub struct PreTotalBitCount(pub Vec<Option<u64>>);
+impl PropertyQuery for PreTotalBitCount {
+    fn associated_command() -> Command {
+        Command::DTV_STAT_PRE_TOTAL_BIT_COUNT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(count_stats(u))
+    }
+}
+impl PreTotalBitCount {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<u64> {
+        self.0.first().copied().flatten()
+    }
+}
+
+// --
+
+#[derive(Debug)]
+pub struct PostErrorBitCount(pub Vec<Option<u64>>);
+impl PropertyQuery for PostErrorBitCount {
+    fn associated_command() -> Command {
+        Command::DTV_STAT_POST_ERROR_BIT_COUNT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(count_stats(u))
+    }
+}
+impl PostErrorBitCount {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<u64> {
+        self.0.first().copied().flatten()
+    }
+}
 
 // --
 
 #[derive(Debug)]
-pub struct TotalBlockCount(pub Option<u64>);
+pub struct PostTotalBitCount(pub Vec<Option<u64>>);
+impl PropertyQuery for PostTotalBitCount {
+    fn associated_command() -> Command {
+        Command::DTV_STAT_POST_TOTAL_BIT_COUNT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(count_stats(u))
+    }
+}
+impl PostTotalBitCount {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<u64> {
+        self.0.first().copied().flatten()
+    }
+}
+
+// --
+
+#[derive(Debug)]
+pub struct ErrorBlockCount(pub Vec<Option<u64>>);
+impl PropertyQuery for ErrorBlockCount {
+    fn associated_command() -> Command {
+        Command::DTV_STAT_ERROR_BLOCK_COUNT
+    }
+
+    fn from_property(u: DtvPropertyUnion) -> Self {
+        Self(count_stats(u))
+    }
+}
+impl ErrorBlockCount {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<u64> {
+        self.0.first().copied().flatten()
+    }
+}
+
+// --
+
+#[derive(Debug)]
+pub struct TotalBlockCount(pub Vec<Option<u64>>);
 impl PropertyQuery for TotalBlockCount {
     fn associated_command() -> Command {
         Command::DTV_STAT_TOTAL_BLOCK_COUNT
     }
 
     fn from_property(u: DtvPropertyUnion) -> Self {
-        let stats = unsafe { u.st };
-        assert_eq!(stats.len, 1);
-        let stat = stats.stat[0];
-        let scale = FeCapScaleParams::try_from(stat.scale).expect("unexpected value for stat type");
-        let res = match StatResult::from(scale, stat.value) {
-            Some(v) => v,
-            None => return Self(None),
-        };
-        match res {
-            StatResult::Value(_) => panic!("expected a count, not a value"),
-            StatResult::Count(count) => Self(Some(count)),
-        }
+        Self(count_stats(u))
+    }
+}
+impl TotalBlockCount {
+    /// Global measurement, regardless of layers.
+    pub fn global(&self) -> Option<u64> {
+        self.0.first().copied().flatten()
+    }
+}
+
+//
+// ----- Error rates
+
+/// Bit Error Rate from the global error and total bit counts, between 0 and 1.
+///
+/// `None` if either count is unavailable, or if no bits were measured yet.
+pub fn ber(pre_error: &PreErrorBitCount, pre_total: &PreTotalBitCount) -> Option<f64> {
+    ratio(pre_error.global()?, pre_total.global()?)
+}
+
+/// Packet (block) Error Rate from the global error and total block counts, between 0 and 1.
+///
+/// `None` if either count is unavailable, or if no blocks were measured yet.
+pub fn per(err_blocks: &ErrorBlockCount, total_blocks: &TotalBlockCount) -> Option<f64> {
+    ratio(err_blocks.global()?, total_blocks.global()?)
+}
+
+pub(crate) fn ratio(errors: u64, total: u64) -> Option<f64> {
+    if total == 0 {
+        return None;
     }
+    Some(errors as f64 / total as f64)
 }
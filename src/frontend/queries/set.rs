@@ -1,9 +1,14 @@
-use crate::frontend::{
-    data::{
-        FeCodeRate, FeDeliverySystem, FeGuardInterval, FeModulation, FeSpectralInversion,
-        FeTransmitMode,
+use crate::{
+    error::{TuneRequestError, UnsupportedCap},
+    frontend::{
+        data::{
+            FeCaps, FeCodeRate, FeDeliverySystem, FeGuardInterval, FeHierarchy, FeInterleaving,
+            FeModulation, FePilot, FeRolloff, FeSpectralInversion, FeTransmitMode, SecToneMode,
+            SecVoltage,
+        },
+        lnb::{Lnb, Polarization},
+        property::{Command, DtvProperty},
     },
-    property::{Command, DtvProperty},
 };
 
 //
@@ -12,6 +17,41 @@ pub trait SetPropertyQuery {
     fn property(self) -> DtvProperty;
 }
 
+/// Accumulates properties to send in a single batch.
+///
+/// The properties are kept contiguous, so [as_mut_slice](Self::as_mut_slice) can be passed
+/// to [get_set_properties_raw](crate::frontend::functions::get_set_properties_raw).
+#[derive(Debug, Default, Clone)]
+pub struct DtvPropertyList(Vec<DtvProperty>);
+
+impl DtvPropertyList {
+    pub fn new() -> DtvPropertyList {
+        DtvPropertyList::default()
+    }
+
+    pub fn push<T: SetPropertyQuery>(&mut self, query: T) {
+        self.0.push(query.property());
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [DtvProperty] {
+        &mut self.0
+    }
+}
+
+impl From<DtvPropertyList> for Vec<DtvProperty> {
+    fn from(list: DtvPropertyList) -> Self {
+        list.0
+    }
+}
+
 //
 // ----- Individual queries
 
@@ -64,6 +104,7 @@ impl SetPropertyQuery for Modulation {
 // --
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BandwidthHz {
     _1_172MHz,
     _5MHz,
@@ -83,6 +124,19 @@ impl BandwidthHz {
             BandwidthHz::_10MHz => 10000000,
         }
     }
+
+    /// Matches a bandwidth in Hz, such as 8000000, to a known value. Non-standard bandwidths give `None`.
+    pub fn from_hz(hz: u32) -> Option<BandwidthHz> {
+        Some(match hz {
+            1712000 => BandwidthHz::_1_172MHz,
+            5000000 => BandwidthHz::_5MHz,
+            6000000 => BandwidthHz::_6MHz,
+            7000000 => BandwidthHz::_7MHz,
+            8000000 => BandwidthHz::_8MHz,
+            10000000 => BandwidthHz::_10MHz,
+            _ => return None,
+        })
+    }
 }
 impl SetPropertyQuery for BandwidthHz {
     fn property(self) -> DtvProperty {
@@ -106,7 +160,18 @@ impl SetPropertyQuery for Inversion {
 
 // --
 
-pub struct SymbolRate {}
+/// Symbol rate, in symbols per second.
+pub struct SymbolRate(u32);
+impl SymbolRate {
+    pub fn new(rate: u32) -> SymbolRate {
+        SymbolRate(rate)
+    }
+}
+impl SetPropertyQuery for SymbolRate {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_SYMBOL_RATE, self.0)
+    }
+}
 
 // --
 
@@ -124,11 +189,31 @@ impl SetPropertyQuery for InnerFec {
 
 // --
 
-pub struct Pilot {}
+pub struct Pilot(FePilot);
+impl Pilot {
+    pub fn new(pilot: FePilot) -> Pilot {
+        Pilot(pilot)
+    }
+}
+impl SetPropertyQuery for Pilot {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_PILOT, self.0 as u32)
+    }
+}
 
 // --
 
-pub struct Rolloff {}
+pub struct Rolloff(FeRolloff);
+impl Rolloff {
+    pub fn new(rolloff: FeRolloff) -> Rolloff {
+        Rolloff(rolloff)
+    }
+}
+impl SetPropertyQuery for Rolloff {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ROLLOFF, self.0 as u32)
+    }
+}
 
 // --
 
@@ -146,6 +231,24 @@ impl SetPropertyQuery for DeliverySystem {
 
 // --
 
+/// Sends a DiSEqC message as part of a property batch, through the ``u.buffer`` variant.
+///
+/// Few drivers implement this property, [Frontend::diseqc_send_master_cmd](crate::frontend::wrapper::Frontend::diseqc_send_master_cmd) is the reliable way.
+pub struct DiseqcMaster(DtvProperty);
+impl DiseqcMaster {
+    /// Panics if the message is longer than [DTV_BUFFER_MAX_LEN](crate::frontend::property::DTV_BUFFER_MAX_LEN) bytes.
+    pub fn new(msg: &[u8]) -> DiseqcMaster {
+        DiseqcMaster(DtvProperty::new_buffer(Command::DTV_DISEQC_MASTER, msg))
+    }
+}
+impl SetPropertyQuery for DiseqcMaster {
+    fn property(self) -> DtvProperty {
+        self.0
+    }
+}
+
+// --
+
 // Special
 pub struct Voltage {}
 
@@ -156,10 +259,10 @@ pub struct Tone {}
 
 // --
 
-pub struct CodeRateHp(FeTransmitMode);
+pub struct CodeRateHp(FeCodeRate);
 impl CodeRateHp {
-    pub fn new(mode: FeTransmitMode) -> CodeRateHp {
-        CodeRateHp(mode)
+    pub fn new(rate: FeCodeRate) -> CodeRateHp {
+        CodeRateHp(rate)
     }
 }
 impl SetPropertyQuery for CodeRateHp {
@@ -170,10 +273,10 @@ impl SetPropertyQuery for CodeRateHp {
 
 // --
 
-pub struct CodeRateLp(FeTransmitMode);
+pub struct CodeRateLp(FeCodeRate);
 impl CodeRateLp {
-    pub fn new(mode: FeTransmitMode) -> CodeRateLp {
-        CodeRateLp(mode)
+    pub fn new(rate: FeCodeRate) -> CodeRateLp {
+        CodeRateLp(rate)
     }
 }
 impl SetPropertyQuery for CodeRateLp {
@@ -198,14 +301,911 @@ impl SetPropertyQuery for GuardInterval {
 
 // --
 
-pub struct TransmissionMode {}
+pub struct TransmissionMode(FeTransmitMode);
+impl TransmissionMode {
+    pub fn new(mode: FeTransmitMode) -> TransmissionMode {
+        TransmissionMode(mode)
+    }
+}
+impl SetPropertyQuery for TransmissionMode {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_TRANSMISSION_MODE, self.0 as u32)
+    }
+}
+
+// --
+
+pub struct Hierarchy(FeHierarchy);
+impl Hierarchy {
+    pub fn new(hierarchy: FeHierarchy) -> Hierarchy {
+        Hierarchy(hierarchy)
+    }
+}
+impl SetPropertyQuery for Hierarchy {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_HIERARCHY, self.0 as u32)
+    }
+}
+
+// --
+
+pub struct Interleaving(FeInterleaving);
+impl Interleaving {
+    pub fn new(interleaving: FeInterleaving) -> Interleaving {
+        Interleaving(interleaving)
+    }
+}
+impl SetPropertyQuery for Interleaving {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_INTERLEAVING, self.0 as u32)
+    }
+}
+
+// --
+
+/// Selects a stream in a multistream transponder (PLP for DVB-T2, ISI for DVB-S2...).
+pub struct StreamId(u32);
+impl StreamId {
+    pub fn new(id: u32) -> StreamId {
+        StreamId(id)
+    }
+}
+impl SetPropertyQuery for StreamId {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_STREAM_ID, self.0)
+    }
+}
 
 // --
 
-pub struct Hierarchy {}
+/// PLP to receive, for old DVB-T2 drivers that predate ``DTV_STREAM_ID``.
+///
+/// New code should use [StreamId], which all current drivers support.
+pub struct Dvbt2PlpIdLegacy(u32);
+impl Dvbt2PlpIdLegacy {
+    pub fn new(id: u32) -> Dvbt2PlpIdLegacy {
+        Dvbt2PlpIdLegacy(id)
+    }
+}
+impl SetPropertyQuery for Dvbt2PlpIdLegacy {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_DVBT2_PLP_ID_LEGACY, self.0)
+    }
+}
 
 // --
 
-pub struct Interleaving {}
+/// Whether the ISDB-T transmission is in partial reception mode (the center segment is decodable alone, for 1-seg).
+pub struct IsdbtPartialReception(bool);
+impl IsdbtPartialReception {
+    pub fn new(partial_reception: bool) -> IsdbtPartialReception {
+        IsdbtPartialReception(partial_reception)
+    }
+}
+impl SetPropertyQuery for IsdbtPartialReception {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ISDBT_PARTIAL_RECEPTION, self.0 as u32)
+    }
+}
 
-// TODO: ISDB-T, Multistream, Physical layer scrambling, ATSC-MH
+// --
+
+/// Bitmask of the ISDB-T layers to decode: bit 0 is layer A, bit 1 is layer B, bit 2 is layer C.
+pub struct IsdbtLayerEnabled(u8);
+impl IsdbtLayerEnabled {
+    pub fn new(mask: u8) -> IsdbtLayerEnabled {
+        IsdbtLayerEnabled(mask)
+    }
+}
+impl SetPropertyQuery for IsdbtLayerEnabled {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ISDBT_LAYER_ENABLED, self.0 as u32)
+    }
+}
+
+// --
+
+/// Switches the frontend to ISDB-Tsb (sound broadcasting) instead of ISDB-T.
+pub struct IsdbtSoundBroadcasting(bool);
+impl IsdbtSoundBroadcasting {
+    pub fn new(sound_broadcasting: bool) -> IsdbtSoundBroadcasting {
+        IsdbtSoundBroadcasting(sound_broadcasting)
+    }
+}
+impl SetPropertyQuery for IsdbtSoundBroadcasting {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ISDBT_SOUND_BROADCASTING, self.0 as u32)
+    }
+}
+
+// --
+
+/// ISDB-Tsb sub-channel to demodulate, from 0 to 41.
+pub struct IsdbtSbSubchannelId(u8);
+impl IsdbtSbSubchannelId {
+    pub fn new(id: u8) -> IsdbtSbSubchannelId {
+        IsdbtSbSubchannelId(id)
+    }
+}
+impl SetPropertyQuery for IsdbtSbSubchannelId {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ISDBT_SB_SUBCHANNEL_ID, self.0 as u32)
+    }
+}
+
+// --
+
+/// Index of the segment to demodulate among the connected ISDB-Tsb segments.
+pub struct IsdbtSbSegmentIdx(u8);
+impl IsdbtSbSegmentIdx {
+    pub fn new(index: u8) -> IsdbtSbSegmentIdx {
+        IsdbtSbSegmentIdx(index)
+    }
+}
+impl SetPropertyQuery for IsdbtSbSegmentIdx {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ISDBT_SB_SEGMENT_IDX, self.0 as u32)
+    }
+}
+
+// --
+
+/// Total number of connected ISDB-Tsb segments, from 1 to 13.
+pub struct IsdbtSbSegmentCount(u8);
+impl IsdbtSbSegmentCount {
+    pub fn new(count: u8) -> IsdbtSbSegmentCount {
+        IsdbtSbSegmentCount(count)
+    }
+}
+impl SetPropertyQuery for IsdbtSbSegmentCount {
+    fn property(self) -> DtvProperty {
+        DtvProperty::new_data(Command::DTV_ISDBT_SB_SEGMENT_COUNT, self.0 as u32)
+    }
+}
+
+// TODO: Physical layer scrambling, ATSC-MH
+
+//
+// ----- ISDB-T layers
+
+/// Hierarchical layer of an ISDB-T transmission.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IsdbtLayerId {
+    A,
+    B,
+    C,
+}
+
+impl IsdbtLayerId {
+    fn commands(self) -> [Command; 4] {
+        match self {
+            IsdbtLayerId::A => [
+                Command::DTV_ISDBT_LAYERA_FEC,
+                Command::DTV_ISDBT_LAYERA_MODULATION,
+                Command::DTV_ISDBT_LAYERA_SEGMENT_COUNT,
+                Command::DTV_ISDBT_LAYERA_TIME_INTERLEAVING,
+            ],
+            IsdbtLayerId::B => [
+                Command::DTV_ISDBT_LAYERB_FEC,
+                Command::DTV_ISDBT_LAYERB_MODULATION,
+                Command::DTV_ISDBT_LAYERB_SEGMENT_COUNT,
+                Command::DTV_ISDBT_LAYERB_TIME_INTERLEAVING,
+            ],
+            IsdbtLayerId::C => [
+                Command::DTV_ISDBT_LAYERC_FEC,
+                Command::DTV_ISDBT_LAYERC_MODULATION,
+                Command::DTV_ISDBT_LAYERC_SEGMENT_COUNT,
+                Command::DTV_ISDBT_LAYERC_TIME_INTERLEAVING,
+            ],
+        }
+    }
+
+    fn mask(self) -> u8 {
+        match self {
+            IsdbtLayerId::A => 0b001,
+            IsdbtLayerId::B => 0b010,
+            IsdbtLayerId::C => 0b100,
+        }
+    }
+}
+
+/// Parameters of a single ISDB-T layer.
+///
+/// FEC and modulation default to ``*_AUTO``. Segment count and time interleaving are only sent if set.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IsdbtLayerParams {
+    fec: Option<FeCodeRate>,
+    modulation: Option<FeModulation>,
+    segment_count: Option<u8>,
+    time_interleaving: Option<u8>,
+}
+
+impl IsdbtLayerParams {
+    pub fn new() -> IsdbtLayerParams {
+        IsdbtLayerParams::default()
+    }
+
+    pub fn fec(mut self, fec: FeCodeRate) -> Self {
+        self.fec = Some(fec);
+        self
+    }
+
+    /// Either DQPSK, QPSK, QAM_16 or QAM_64.
+    pub fn modulation(mut self, modulation: FeModulation) -> Self {
+        self.modulation = Some(modulation);
+        self
+    }
+
+    /// Number of segments of the layer, from 0 to 13.
+    pub fn segment_count(mut self, count: u8) -> Self {
+        self.segment_count = Some(count);
+        self
+    }
+
+    /// Time interleaving length, either 0, 1, 2 or 4.
+    pub fn time_interleaving(mut self, interleaving: u8) -> Self {
+        self.time_interleaving = Some(interleaving);
+        self
+    }
+
+    fn properties(self, layer: IsdbtLayerId) -> impl Iterator<Item = DtvProperty> {
+        let [fec, modulation, segment_count, time_interleaving] = layer.commands();
+        [
+            Some(DtvProperty::new_data(
+                fec,
+                self.fec.unwrap_or(FeCodeRate::FEC_AUTO) as u32,
+            )),
+            Some(DtvProperty::new_data(
+                modulation,
+                self.modulation.unwrap_or(FeModulation::QAM_AUTO) as u32,
+            )),
+            self.segment_count
+                .map(|count| DtvProperty::new_data(segment_count, count as u32)),
+            self.time_interleaving
+                .map(|length| DtvProperty::new_data(time_interleaving, length as u32)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Builds the layer configuration of an ISDB-T transmission.
+///
+/// Only the layers that were given parameters are enabled (``DTV_ISDBT_LAYER_ENABLED``).
+/// If no layer is given, the mask is not sent and the frontend decodes all layers.
+/// The resulting properties are meant to be sent along with the frequency and bandwidth, before ``DTV_TUNE``.
+#[derive(Debug, Clone, Default)]
+pub struct IsdbtLayer {
+    partial_reception: Option<bool>,
+    layers: Vec<(IsdbtLayerId, IsdbtLayerParams)>,
+}
+
+impl IsdbtLayer {
+    pub fn new() -> IsdbtLayer {
+        IsdbtLayer::default()
+    }
+
+    pub fn partial_reception(mut self, partial_reception: bool) -> Self {
+        self.partial_reception = Some(partial_reception);
+        self
+    }
+
+    /// Sets the parameters of a layer, replacing previous ones for the same layer.
+    pub fn layer(mut self, layer: IsdbtLayerId, params: IsdbtLayerParams) -> Self {
+        self.layers.retain(|(id, _)| *id != layer);
+        self.layers.push((layer, params));
+        self
+    }
+
+    pub fn build(self) -> Vec<DtvProperty> {
+        let mut properties = Vec::new();
+        if let Some(partial_reception) = self.partial_reception {
+            properties.push(IsdbtPartialReception::new(partial_reception).property());
+        }
+        if !self.layers.is_empty() {
+            let mask = self.layers.iter().fold(0, |mask, (id, _)| mask | id.mask());
+            properties.push(IsdbtLayerEnabled::new(mask).property());
+        }
+        for (id, params) in self.layers {
+            properties.extend(params.properties(id));
+        }
+
+        properties
+    }
+}
+
+//
+// ----- ISDB-Tsb
+
+/// Spacing between two ISDB-Tsb segments, in Hz (6MHz / 14, often rounded to 429kHz).
+pub const ISDBT_SB_SEGMENT_WIDTH_HZ: u32 = 428_571;
+
+/// Builds the properties selecting an ISDB-Tsb (1-seg or 3-seg sound broadcasting) segment.
+///
+/// Several segments are usually transmitted side by side, each [ISDBT_SB_SEGMENT_WIDTH_HZ] wide.
+/// The frequency to tune is the center of all connected segments, and the segment to demodulate is then picked by its index:
+/// segment `i` is centered `(i - (count - 1) / 2) * 429kHz` away from that frequency.
+#[derive(Debug, Clone, Default)]
+pub struct IsdbtSb {
+    subchannel_id: Option<u8>,
+    segment_idx: Option<u8>,
+    segment_count: Option<u8>,
+}
+
+impl IsdbtSb {
+    pub fn new() -> IsdbtSb {
+        IsdbtSb::default()
+    }
+
+    pub fn subchannel_id(mut self, id: u8) -> Self {
+        self.subchannel_id = Some(id);
+        self
+    }
+
+    pub fn segment_idx(mut self, index: u8) -> Self {
+        self.segment_idx = Some(index);
+        self
+    }
+
+    pub fn segment_count(mut self, count: u8) -> Self {
+        self.segment_count = Some(count);
+        self
+    }
+
+    /// Produces the properties to send along with the frequency, before ``DTV_TUNE``.
+    pub fn build(self) -> Result<Vec<DtvProperty>, TuneRequestError> {
+        if let (Some(index), Some(count)) = (self.segment_idx, self.segment_count)
+            && index >= count
+        {
+            return Err(TuneRequestError::SegmentOutOfRange { index, count });
+        }
+
+        let mut properties = vec![IsdbtSoundBroadcasting::new(true).property()];
+        if let Some(id) = self.subchannel_id {
+            properties.push(IsdbtSbSubchannelId::new(id).property());
+        }
+        if let Some(index) = self.segment_idx {
+            properties.push(IsdbtSbSegmentIdx::new(index).property());
+        }
+        if let Some(count) = self.segment_count {
+            properties.push(IsdbtSbSegmentCount::new(count).property());
+        }
+
+        Ok(properties)
+    }
+}
+
+//
+// ----- Tune requests
+
+/// Builds all properties needed to tune to a DVB-T or DVB-T2 channel.
+///
+/// Parameters that are not set are left for the frontend to detect (``*_AUTO``).
+/// If no bandwidth is set, the property is not sent and the driver default is used.
+#[derive(Debug, Clone, Default)]
+pub struct DvbtTuneRequest {
+    t2: bool,
+    frequency: Option<u32>,
+    bandwidth: Option<BandwidthHz>,
+    code_rate_hp: Option<FeCodeRate>,
+    code_rate_lp: Option<FeCodeRate>,
+    modulation: Option<FeModulation>,
+    transmission_mode: Option<FeTransmitMode>,
+    guard_interval: Option<FeGuardInterval>,
+    hierarchy: Option<FeHierarchy>,
+    inversion: Option<FeSpectralInversion>,
+    stream_id: Option<u32>,
+    legacy_plp_id: bool,
+}
+
+impl DvbtTuneRequest {
+    pub fn new() -> DvbtTuneRequest {
+        DvbtTuneRequest::default()
+    }
+
+    /// Tune using DVB-T2 instead of DVB-T.
+    pub fn dvbt2(mut self) -> Self {
+        self.t2 = true;
+        self
+    }
+
+    /// Center frequency of the channel, in Hz.
+    pub fn frequency(mut self, frequency: u32) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    pub fn bandwidth(mut self, bandwidth: BandwidthHz) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    pub fn code_rate_hp(mut self, rate: FeCodeRate) -> Self {
+        self.code_rate_hp = Some(rate);
+        self
+    }
+
+    pub fn code_rate_lp(mut self, rate: FeCodeRate) -> Self {
+        self.code_rate_lp = Some(rate);
+        self
+    }
+
+    pub fn modulation(mut self, modulation: FeModulation) -> Self {
+        self.modulation = Some(modulation);
+        self
+    }
+
+    pub fn transmission_mode(mut self, mode: FeTransmitMode) -> Self {
+        self.transmission_mode = Some(mode);
+        self
+    }
+
+    pub fn guard_interval(mut self, interval: FeGuardInterval) -> Self {
+        self.guard_interval = Some(interval);
+        self
+    }
+
+    pub fn hierarchy(mut self, hierarchy: FeHierarchy) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
+    }
+
+    pub fn inversion(mut self, inversion: FeSpectralInversion) -> Self {
+        self.inversion = Some(inversion);
+        self
+    }
+
+    /// PLP to receive, only used for DVB-T2.
+    pub fn stream_id(mut self, id: u32) -> Self {
+        self.stream_id = Some(id);
+        self
+    }
+
+    /// Also sends the PLP as ``DTV_DVBT2_PLP_ID_LEGACY``, for old DVB-T2 drivers that ignore ``DTV_STREAM_ID``.
+    pub fn legacy_plp_id(mut self) -> Self {
+        self.legacy_plp_id = true;
+        self
+    }
+
+    /// Checks that a frontend with given capabilities can handle this request, including parameters left to ``*_AUTO``.
+    pub fn validate(&self, caps: FeCaps) -> Result<(), UnsupportedCap> {
+        if self.t2 && !caps.can_2g_modulation() {
+            return Err(UnsupportedCap::SecondGeneration);
+        }
+        if self.t2 && self.stream_id.is_some() && !caps.can_multistream() {
+            return Err(UnsupportedCap::Multistream);
+        }
+        check_modulation(caps, self.modulation.unwrap_or(FeModulation::QAM_AUTO))?;
+        check_fec(caps, self.code_rate_hp.unwrap_or(FeCodeRate::FEC_AUTO))?;
+        check_fec(caps, self.code_rate_lp.unwrap_or(FeCodeRate::FEC_AUTO))?;
+        if self.transmission_mode.is_none() && !caps.can_transmission_mode_auto() {
+            return Err(UnsupportedCap::Auto("transmission mode"));
+        }
+        if self.guard_interval.is_none() && !caps.can_guard_interval_auto() {
+            return Err(UnsupportedCap::Auto("guard interval"));
+        }
+        if self.hierarchy.is_none() && !caps.can_hierarchy_auto() {
+            return Err(UnsupportedCap::Auto("hierarchy"));
+        }
+        check_inversion(caps, self.inversion)
+    }
+
+    /// Produces the properties to send, in order, ending with ``DTV_TUNE``.
+    ///
+    /// Fails if no frequency was set, or if a stream id was set for DVB-T.
+    pub fn build(self) -> Result<Vec<DtvProperty>, TuneRequestError> {
+        let frequency = self.frequency.ok_or(TuneRequestError::MissingFrequency)?;
+        let system = if self.t2 {
+            FeDeliverySystem::DVBT2
+        } else {
+            FeDeliverySystem::DVBT
+        };
+        if !self.t2 && self.stream_id.is_some() {
+            return Err(TuneRequestError::InvalidForDeliverySystem {
+                parameter: "stream id",
+                system,
+            });
+        }
+
+        let mut properties = vec![
+            DeliverySystem::new(system).property(),
+            Frequency::new(frequency).property(),
+        ];
+        if let Some(bandwidth) = self.bandwidth {
+            properties.push(bandwidth.property());
+        }
+        properties.extend([
+            CodeRateHp::new(self.code_rate_hp.unwrap_or(FeCodeRate::FEC_AUTO)).property(),
+            CodeRateLp::new(self.code_rate_lp.unwrap_or(FeCodeRate::FEC_AUTO)).property(),
+            Modulation::new(self.modulation.unwrap_or(FeModulation::QAM_AUTO)).property(),
+            TransmissionMode::new(
+                self.transmission_mode
+                    .unwrap_or(FeTransmitMode::TRANSMISSION_MODE_AUTO),
+            )
+            .property(),
+            GuardInterval::new(
+                self.guard_interval
+                    .unwrap_or(FeGuardInterval::GUARD_INTERVAL_AUTO),
+            )
+            .property(),
+            Hierarchy::new(self.hierarchy.unwrap_or(FeHierarchy::HIERARCHY_AUTO)).property(),
+            Inversion::new(
+                self.inversion
+                    .unwrap_or(FeSpectralInversion::INVERSION_AUTO),
+            )
+            .property(),
+        ]);
+        if let Some(id) = self.stream_id {
+            properties.push(StreamId::new(id).property());
+            if self.legacy_plp_id {
+                properties.push(Dvbt2PlpIdLegacy::new(id).property());
+            }
+        }
+        properties.push(Tune {}.property());
+
+        Ok(properties)
+    }
+}
+
+/// Builds everything needed to tune to a DVB-S or DVB-S2 transponder through an LNB.
+///
+/// The LNB math is handled here: the transponder frequency is converted to the Intermediate Frequency,
+/// and the voltage (polarization) and tone (band) to apply are computed.
+/// Parameters that are not set are left for the frontend to detect (``*_AUTO``), except modulation which defaults to QPSK.
+#[derive(Debug, Clone)]
+pub struct DvbsTuneRequest {
+    lnb: Lnb,
+    s2: bool,
+    frequency: Option<u32>,
+    symbol_rate: Option<u32>,
+    polarization: Option<Polarization>,
+    fec: Option<FeCodeRate>,
+    modulation: Option<FeModulation>,
+    rolloff: Option<FeRolloff>,
+    pilot: Option<FePilot>,
+    inversion: Option<FeSpectralInversion>,
+}
+
+/// Result of a [DvbsTuneRequest].
+///
+/// Voltage and tone must be applied to the frontend first, then properties can be sent.
+#[derive(Debug, Clone)]
+pub struct DvbsTune {
+    pub voltage: SecVoltage,
+    pub tone: SecToneMode,
+    /// Properties to send, in order, ending with ``DTV_TUNE``.
+    pub properties: Vec<DtvProperty>,
+}
+
+impl DvbsTuneRequest {
+    pub fn new(lnb: Lnb) -> DvbsTuneRequest {
+        DvbsTuneRequest {
+            lnb,
+            s2: false,
+            frequency: None,
+            symbol_rate: None,
+            polarization: None,
+            fec: None,
+            modulation: None,
+            rolloff: None,
+            pilot: None,
+            inversion: None,
+        }
+    }
+
+    /// Tune using DVB-S2 instead of DVB-S. Rolloff and pilot can only be set for DVB-S2.
+    pub fn dvbs2(mut self) -> Self {
+        self.s2 = true;
+        self
+    }
+
+    /// Frequency of the transponder (not the intermediate frequency), in kHz.
+    pub fn frequency(mut self, frequency: u32) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Symbol rate, in symbols per second.
+    pub fn symbol_rate(mut self, rate: u32) -> Self {
+        self.symbol_rate = Some(rate);
+        self
+    }
+
+    pub fn polarization(mut self, polarization: Polarization) -> Self {
+        self.polarization = Some(polarization);
+        self
+    }
+
+    pub fn fec(mut self, fec: FeCodeRate) -> Self {
+        self.fec = Some(fec);
+        self
+    }
+
+    pub fn modulation(mut self, modulation: FeModulation) -> Self {
+        self.modulation = Some(modulation);
+        self
+    }
+
+    pub fn rolloff(mut self, rolloff: FeRolloff) -> Self {
+        self.rolloff = Some(rolloff);
+        self
+    }
+
+    pub fn pilot(mut self, pilot: FePilot) -> Self {
+        self.pilot = Some(pilot);
+        self
+    }
+
+    pub fn inversion(mut self, inversion: FeSpectralInversion) -> Self {
+        self.inversion = Some(inversion);
+        self
+    }
+
+    /// Checks that a frontend with given capabilities can handle this request, including parameters left to ``*_AUTO``.
+    pub fn validate(&self, caps: FeCaps) -> Result<(), UnsupportedCap> {
+        if self.s2 && !caps.can_2g_modulation() {
+            return Err(UnsupportedCap::SecondGeneration);
+        }
+        check_modulation(caps, self.modulation.unwrap_or(FeModulation::QPSK))?;
+        check_fec(caps, self.fec.unwrap_or(FeCodeRate::FEC_AUTO))?;
+        check_inversion(caps, self.inversion)
+    }
+
+    /// Computes the voltage, tone and properties to apply.
+    ///
+    /// Fails if frequency, symbol rate or polarization were not set, or if rolloff or pilot were set for DVB-S.
+    pub fn build(self) -> Result<DvbsTune, TuneRequestError> {
+        let frequency = self.frequency.ok_or(TuneRequestError::MissingFrequency)?;
+        let symbol_rate = self
+            .symbol_rate
+            .ok_or(TuneRequestError::MissingSymbolRate)?;
+        let polarization = self
+            .polarization
+            .ok_or(TuneRequestError::MissingPolarization)?;
+
+        let (intermediate_frequency, band) = self.lnb.downconvert(frequency);
+        let system = if self.s2 {
+            FeDeliverySystem::DVBS2
+        } else {
+            FeDeliverySystem::DVBS
+        };
+        if !self.s2 {
+            let parameter = if self.rolloff.is_some() {
+                Some("rolloff")
+            } else if self.pilot.is_some() {
+                Some("pilot")
+            } else {
+                None
+            };
+            if let Some(parameter) = parameter {
+                return Err(TuneRequestError::InvalidForDeliverySystem { parameter, system });
+            }
+        }
+
+        let mut properties = vec![
+            DeliverySystem::new(system).property(),
+            Frequency::new(intermediate_frequency).property(),
+            SymbolRate::new(symbol_rate).property(),
+            InnerFec::new(self.fec.unwrap_or(FeCodeRate::FEC_AUTO)).property(),
+            Modulation::new(self.modulation.unwrap_or(FeModulation::QPSK)).property(),
+            Inversion::new(
+                self.inversion
+                    .unwrap_or(FeSpectralInversion::INVERSION_AUTO),
+            )
+            .property(),
+        ];
+        if self.s2 {
+            properties.extend([
+                Rolloff::new(self.rolloff.unwrap_or(FeRolloff::ROLLOFF_AUTO)).property(),
+                Pilot::new(self.pilot.unwrap_or(FePilot::PILOT_AUTO)).property(),
+            ]);
+        }
+        properties.push(Tune {}.property());
+
+        Ok(DvbsTune {
+            voltage: polarization.voltage(),
+            tone: band.tone(),
+            properties,
+        })
+    }
+}
+
+/// Builds all properties needed to tune to a DVB-C channel.
+///
+/// Annex A is used unless another annex is selected.
+/// Annex B derives its symbol rate and FEC from the modulation, so these can only be set for Annex A and C.
+/// Modulation defaults to QAM_64 for Annex B, and is left for the frontend to detect (``QAM_AUTO``) otherwise.
+#[derive(Debug, Clone)]
+pub struct DvbcTuneRequest {
+    system: FeDeliverySystem,
+    frequency: Option<u32>,
+    symbol_rate: Option<u32>,
+    modulation: Option<FeModulation>,
+    fec: Option<FeCodeRate>,
+    inversion: Option<FeSpectralInversion>,
+}
+
+impl Default for DvbcTuneRequest {
+    fn default() -> Self {
+        DvbcTuneRequest {
+            system: FeDeliverySystem::DVBC_ANNEX_A,
+            frequency: None,
+            symbol_rate: None,
+            modulation: None,
+            fec: None,
+            inversion: None,
+        }
+    }
+}
+
+impl DvbcTuneRequest {
+    pub fn new() -> DvbcTuneRequest {
+        DvbcTuneRequest::default()
+    }
+
+    /// Tune using ITU-T J.83 Annex B (North-American cable) instead of Annex A.
+    pub fn annex_b(mut self) -> Self {
+        self.system = FeDeliverySystem::DVBC_ANNEX_B;
+        self
+    }
+
+    /// Tune using ITU-T J.83 Annex C (Japanese cable) instead of Annex A.
+    pub fn annex_c(mut self) -> Self {
+        self.system = FeDeliverySystem::DVBC_ANNEX_C;
+        self
+    }
+
+    /// Center frequency of the channel, in Hz.
+    pub fn frequency(mut self, frequency: u32) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Symbol rate, in symbols per second. Not used by Annex B.
+    pub fn symbol_rate(mut self, rate: u32) -> Self {
+        self.symbol_rate = Some(rate);
+        self
+    }
+
+    /// One of QAM_16 to QAM_256.
+    pub fn modulation(mut self, modulation: FeModulation) -> Self {
+        self.modulation = Some(modulation);
+        self
+    }
+
+    /// Not used by Annex B.
+    pub fn fec(mut self, fec: FeCodeRate) -> Self {
+        self.fec = Some(fec);
+        self
+    }
+
+    pub fn inversion(mut self, inversion: FeSpectralInversion) -> Self {
+        self.inversion = Some(inversion);
+        self
+    }
+
+    /// Produces the properties to send, in order, ending with ``DTV_TUNE``.
+    ///
+    /// Fails if no frequency was set, if no symbol rate was set for Annex A or C,
+    /// or if a symbol rate or FEC was set for Annex B.
+    pub fn build(self) -> Result<Vec<DtvProperty>, TuneRequestError> {
+        let frequency = self.frequency.ok_or(TuneRequestError::MissingFrequency)?;
+        let annex_b = self.system == FeDeliverySystem::DVBC_ANNEX_B;
+        if annex_b {
+            let parameter = if self.symbol_rate.is_some() {
+                Some("symbol rate")
+            } else if self.fec.is_some() {
+                Some("FEC")
+            } else {
+                None
+            };
+            if let Some(parameter) = parameter {
+                return Err(TuneRequestError::InvalidForDeliverySystem {
+                    parameter,
+                    system: self.system,
+                });
+            }
+        }
+        let default_modulation = if annex_b {
+            FeModulation::QAM_64
+        } else {
+            FeModulation::QAM_AUTO
+        };
+
+        let mut properties = vec![
+            DeliverySystem::new(self.system).property(),
+            Frequency::new(frequency).property(),
+            Modulation::new(self.modulation.unwrap_or(default_modulation)).property(),
+            Inversion::new(
+                self.inversion
+                    .unwrap_or(FeSpectralInversion::INVERSION_AUTO),
+            )
+            .property(),
+        ];
+        if !annex_b {
+            let symbol_rate = self
+                .symbol_rate
+                .ok_or(TuneRequestError::MissingSymbolRate)?;
+            properties.extend([
+                SymbolRate::new(symbol_rate).property(),
+                InnerFec::new(self.fec.unwrap_or(FeCodeRate::FEC_AUTO)).property(),
+            ]);
+        }
+        properties.push(Tune {}.property());
+
+        Ok(properties)
+    }
+}
+
+/// Builds all properties needed to tune to an ATSC (North-American terrestrial) channel.
+///
+/// ATSC has no bandwidth or FEC to set: only the frequency is needed, and modulation is always 8-VSB.
+#[derive(Debug, Clone, Default)]
+pub struct AtscTuneRequest {
+    frequency: Option<u32>,
+    inversion: Option<FeSpectralInversion>,
+}
+
+impl AtscTuneRequest {
+    pub fn new() -> AtscTuneRequest {
+        AtscTuneRequest::default()
+    }
+
+    /// Center frequency of the channel, in Hz.
+    pub fn frequency(mut self, frequency: u32) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    pub fn inversion(mut self, inversion: FeSpectralInversion) -> Self {
+        self.inversion = Some(inversion);
+        self
+    }
+
+    /// Produces the properties to send, in order, ending with ``DTV_TUNE``.
+    ///
+    /// Fails if no frequency was set.
+    pub fn build(self) -> Result<Vec<DtvProperty>, TuneRequestError> {
+        let frequency = self.frequency.ok_or(TuneRequestError::MissingFrequency)?;
+
+        Ok(vec![
+            DeliverySystem::new(FeDeliverySystem::ATSC).property(),
+            Frequency::new(frequency).property(),
+            Modulation::new(FeModulation::VSB_8).property(),
+            Inversion::new(
+                self.inversion
+                    .unwrap_or(FeSpectralInversion::INVERSION_AUTO),
+            )
+            .property(),
+            Tune {}.property(),
+        ])
+    }
+}
+
+fn check_modulation(caps: FeCaps, modulation: FeModulation) -> Result<(), UnsupportedCap> {
+    if caps.can_modulation(modulation) {
+        Ok(())
+    } else {
+        Err(UnsupportedCap::Modulation(modulation))
+    }
+}
+
+fn check_fec(caps: FeCaps, rate: FeCodeRate) -> Result<(), UnsupportedCap> {
+    if caps.can_fec(rate) {
+        Ok(())
+    } else {
+        Err(UnsupportedCap::CodeRate(rate))
+    }
+}
+
+fn check_inversion(
+    caps: FeCaps,
+    inversion: Option<FeSpectralInversion>,
+) -> Result<(), UnsupportedCap> {
+    match inversion {
+        None | Some(FeSpectralInversion::INVERSION_AUTO) if !caps.can_inversion_auto() => {
+            Err(UnsupportedCap::Auto("spectral inversion"))
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,718 @@
+//! Owned handle on a frontend device, on top of the raw functions
+
+use std::{
+    fs::OpenOptions,
+    io,
+    os::{
+        fd::{AsFd, BorrowedFd, OwnedFd},
+        unix::fs::OpenOptionsExt as _,
+    },
+    path::Path,
+    sync::{Mutex, MutexGuard, OnceLock, PoisonError},
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+use nix::{
+    errno::Errno,
+    fcntl::{FcntlArg, OFlag, fcntl},
+};
+
+use crate::{
+    error::{DtvError, FrontendInfoError, PropertyError, RangeError, UnsupportedCap, WaitError},
+    fd::{nonblocking_flag, set_nonblocking, wait_readable},
+    frontend::{
+        data::{
+            DTV_IOCTL_MAX_MSGS, DvbDiseqcMasterCmd, DvbFrontendEvent, DvbFrontendInfo,
+            DvbFrontendParameters, FeDeliverySystem, FeStatus, SecMiniCmd, SecToneMode, SecVoltage,
+        },
+        diseqc::DiseqcStep,
+        functions::{
+            diseqc_send_burst, diseqc_send_master_cmd, get_event, get_frontend, get_info,
+            get_set_properties_raw, read_status, set_frontend, set_frontend_tune_mode, set_tone,
+            set_voltage,
+        },
+        property::{Command, DtvProperty},
+        queries::get::{
+            ActiveDeliverySystem, Capability, CapabilityCount, CarrierSignalToNoise,
+            ErrorBlockCount, PostErrorBitCount, PostTotalBitCount, PreErrorBitCount,
+            PreTotalBitCount, PropertyQuery as _, QueryDescription, SignalStrength,
+            TotalBlockCount, ber, per, ratio,
+        },
+        queries::set::{
+            Clear, DeliverySystem, DvbsTuneRequest, DvbtTuneRequest, SetPropertyQuery as _, Tune,
+        },
+    },
+};
+
+/// Time between two status reads while waiting for lock.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// An opened frontend device, such as `/dev/dvb/adapter0/frontend0`.
+#[derive(Debug)]
+pub struct Frontend {
+    fd: OwnedFd,
+    read_only: bool,
+    last_tune: Mutex<LastTune>,
+    info: OnceLock<DvbFrontendInfo>,
+}
+
+impl Frontend {
+    /// Opens the frontend at given path for reading and writing, as required for tuning.
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open(path: impl AsRef<Path>, nonblocking: bool) -> io::Result<Frontend> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(nonblocking_flag(nonblocking))
+            .open(path)?;
+        Ok(Frontend {
+            fd: file.into(),
+            read_only: false,
+            last_tune: Mutex::default(),
+            info: OnceLock::new(),
+        })
+    }
+
+    /// Opens the frontend at given path read-only, for monitoring.
+    ///
+    /// Status and properties can still be read, without taking the tuner from the process that tunes it.
+    /// Anything that changes the frontend fails, [Frontend::set_properties] with [PropertyError::ReadOnly].
+    ///
+    /// If `nonblocking` is set, the device is opened with `O_NONBLOCK`.
+    pub fn open_readonly(path: impl AsRef<Path>, nonblocking: bool) -> io::Result<Frontend> {
+        let file = OpenOptions::new()
+            .read(true)
+            .custom_flags(nonblocking_flag(nonblocking))
+            .open(path)?;
+        Ok(Frontend {
+            fd: file.into(),
+            read_only: true,
+            last_tune: Mutex::default(),
+            info: OnceLock::new(),
+        })
+    }
+
+    /// Whether this frontend was opened read-only, see [Frontend::open_readonly].
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Duplicates the file descriptor, for instance to read statistics from a monitoring thread while another one controls tuning.
+    ///
+    /// Both descriptors refer to the same open file, so flags such as `O_NONBLOCK` are shared.
+    /// Reading status and properties from both is fine, but only one of them should write properties at a time.
+    /// The last tune request is not shared, see [Frontend::with_reinit_recovery].
+    pub fn try_clone(&self) -> io::Result<Frontend> {
+        Ok(Frontend {
+            fd: self.fd.try_clone()?,
+            read_only: self.read_only,
+            last_tune: Mutex::default(),
+            info: self.info.clone(),
+        })
+    }
+
+    /// Switches the frontend between blocking and non-blocking (`O_NONBLOCK`) mode after it was opened.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        set_nonblocking(self.fd.as_fd(), nonblocking)
+    }
+
+    /// Reads the current status of the frontend, such as whether it has lock.
+    pub fn status(&self) -> Result<FeStatus, Errno> {
+        Ok(FeStatus::from(read_status(self.fd.as_fd())?))
+    }
+
+    /// Queries information about this frontend.
+    pub fn get_info(&self) -> Result<DvbFrontendInfo, FrontendInfoError> {
+        get_info(self.fd.as_fd())
+    }
+
+    /// Same as [Frontend::get_info], but only asks the kernel the first time and keeps the result.
+    ///
+    /// The name, capabilities and ranges do not change for a given device. The legacy type and the unit
+    /// of the frequency range follow the delivery system in use when first called: call [Frontend::get_info]
+    /// after switching between satellite and other systems.
+    pub fn info(&self) -> Result<&DvbFrontendInfo, FrontendInfoError> {
+        if let Some(info) = self.info.get() {
+            return Ok(info);
+        }
+        let info = self.get_info()?;
+        Ok(self.info.get_or_init(|| info))
+    }
+
+    /// Checks a DVB-T/T2 tune request against the capabilities advertised by this frontend, before tuning.
+    pub fn validate(&self, request: &DvbtTuneRequest) -> Result<(), UnsupportedCap> {
+        request.validate(self.info()?.caps)
+    }
+
+    /// Checks a DVB-S/S2 tune request against the capabilities advertised by this frontend, before tuning.
+    pub fn validate_dvbs(&self, request: &DvbsTuneRequest) -> Result<(), UnsupportedCap> {
+        request.validate(self.info()?.caps)
+    }
+
+    /// Checks a frequency against the range advertised by this frontend, see [Frontend::info].
+    ///
+    /// The frequency is in the unit of [DTV_FREQUENCY](crate::frontend::property::Command::DTV_FREQUENCY):
+    /// kHz for satellite delivery systems, Hz for the others. Drivers not advertising a range accept any frequency.
+    pub fn check_frequency(&self, frequency: u32) -> Result<(), RangeError> {
+        let info = self.info()?;
+        check_range(
+            "frequency",
+            frequency,
+            info.frequency_min,
+            info.frequency_max,
+        )
+    }
+
+    /// Checks a symbol rate, in symbols per second, against the range advertised by this frontend, see [Frontend::info].
+    ///
+    /// Drivers not advertising a range accept any symbol rate.
+    pub fn check_symbol_rate(&self, symbol_rate: u32) -> Result<(), RangeError> {
+        let info = self.info()?;
+        check_range(
+            "symbol rate",
+            symbol_rate,
+            info.symbol_rate_min,
+            info.symbol_rate_max,
+        )
+    }
+
+    /// Sends all properties in a single batch.
+    pub fn set_properties(&self, properties: &mut [DtvProperty]) -> Result<(), PropertyError> {
+        if self.read_only {
+            return Err(PropertyError::ReadOnly);
+        }
+        get_set_properties_raw(self.fd.as_fd(), true, properties)?;
+
+        if properties
+            .iter()
+            .any(|property| property.cmd == Command::DTV_TUNE as u32)
+        {
+            self.last_tune().tuned(properties);
+        }
+        Ok(())
+    }
+
+    /// Reads all queried properties in a single batch.
+    ///
+    /// Use [PendingQuery::desc](crate::frontend::queries::get::PendingQuery::desc) to describe each query,
+    /// then retrieve the values from the pending queries.
+    pub fn properties(&self, queries: &mut [QueryDescription]) -> Result<(), PropertyError> {
+        let mut properties: Vec<DtvProperty> = queries
+            .iter()
+            .map(|query| {
+                if query.command.is_stat() {
+                    DtvProperty::new_stats(query.command)
+                } else {
+                    DtvProperty::new_empty(query.command)
+                }
+            })
+            .collect();
+        get_set_properties_raw(self.fd.as_fd(), false, &mut properties)?;
+
+        for (query, property) in queries.iter_mut().zip(properties) {
+            *query.property = Some(property);
+        }
+        Ok(())
+    }
+
+    /// Writes properties as they are, for commands that have no typed query yet.
+    ///
+    /// Same as [Frontend::set_properties].
+    pub fn set_raw(&self, properties: &mut [DtvProperty]) -> Result<(), PropertyError> {
+        self.set_properties(properties)
+    }
+
+    /// Reads properties as they are, for commands that have no typed query yet.
+    ///
+    /// Only the command of each property needs to be set, such as with [DtvProperty::new_empty].
+    /// For statistics, use [DtvProperty::new_stats] instead. The kernel fills the value of each property.
+    pub fn get_raw(&self, properties: &mut [DtvProperty]) -> Result<(), PropertyError> {
+        get_set_properties_raw(self.fd.as_fd(), false, properties)
+    }
+
+    /// Reads the extended capability codes of this frontend.
+    ///
+    /// The count `n` is read first, then each of the `n` entries, batched in as few ioctls as possible.
+    /// Drivers which do not implement this mechanism report an error for these commands.
+    pub fn enumerate_capabilities(&self) -> Result<Vec<u32>, DtvError> {
+        let mut count = CapabilityCount::query();
+        self.properties(&mut [count.desc()])?;
+        let count = count.retrieve()?.0;
+
+        let mut entries: Vec<DtvProperty> = (0..count)
+            .map(|index| DtvProperty::new_data(Command::DTV_FE_CAPABILITY, index))
+            .collect();
+        let mut capabilities = Vec::with_capacity(entries.len());
+        for batch in entries.chunks_mut(DTV_IOCTL_MAX_MSGS) {
+            self.get_raw(batch)?;
+            capabilities.extend(
+                batch
+                    .iter()
+                    .map(|entry| Capability::from_property(entry.u).0),
+            );
+        }
+        Ok(capabilities)
+    }
+
+    /// Reads all statistics (signal, CNR, bit and block counts) in a single batch.
+    ///
+    /// A single ioctl is much faster than one per statistic, especially on USB tuners.
+    pub fn read_all_stats(&self) -> Result<Stats, DtvError> {
+        let mut strength = SignalStrength::query();
+        let mut cnr = CarrierSignalToNoise::query();
+        let mut pre_error_bits = PreErrorBitCount::query();
+        let mut pre_total_bits = PreTotalBitCount::query();
+        let mut post_error_bits = PostErrorBitCount::query();
+        let mut post_total_bits = PostTotalBitCount::query();
+        let mut error_blocks = ErrorBlockCount::query();
+        let mut total_blocks = TotalBlockCount::query();
+        self.properties(&mut [
+            strength.desc(),
+            cnr.desc(),
+            pre_error_bits.desc(),
+            pre_total_bits.desc(),
+            post_error_bits.desc(),
+            post_total_bits.desc(),
+            error_blocks.desc(),
+            total_blocks.desc(),
+        ])?;
+
+        Ok(Stats {
+            strength: strength.retrieve()?,
+            cnr: cnr.retrieve()?,
+            pre_error_bits: pre_error_bits.retrieve()?,
+            pre_total_bits: pre_total_bits.retrieve()?,
+            post_error_bits: post_error_bits.retrieve()?,
+            post_total_bits: post_total_bits.retrieve()?,
+            error_blocks: error_blocks.retrieve()?,
+            total_blocks: total_blocks.retrieve()?,
+        })
+    }
+
+    /// Takes a snapshot of the status and signal quality.
+    pub fn signal_snapshot(&self) -> Result<SignalSnapshot, DtvError> {
+        let status = self.status().map_err(DtvError::ReadStatus)?;
+        let stats = self.read_all_stats()?;
+        Ok(SignalSnapshot { status, stats })
+    }
+
+    /// Repeatedly takes signal snapshots, for signal meters and such.
+    ///
+    /// The first snapshot is taken immediately, then each call to `next()` blocks for `interval` before sampling again.
+    pub fn monitor(
+        &self,
+        interval: Duration,
+    ) -> impl Iterator<Item = Result<SignalSnapshot, DtvError>> + '_ {
+        let mut first = true;
+        std::iter::from_fn(move || {
+            if !first {
+                sleep(interval);
+            }
+            first = false;
+            Some(self.signal_snapshot())
+        })
+    }
+
+    /// Switches a multistandard frontend to another delivery system, sending ``DTV_CLEAR`` then ``DTV_DELIVERY_SYSTEM`` in one batch.
+    ///
+    /// Some drivers ignore a delivery system they do not like, see [Frontend::set_delivery_system_checked] to verify the switch.
+    pub fn set_delivery_system(&self, system: FeDeliverySystem) -> Result<(), PropertyError> {
+        self.set_properties(&mut [Clear {}.property(), DeliverySystem::new(system).property()])
+    }
+
+    /// Like [Frontend::set_delivery_system], then reads the delivery system back to make sure the driver switched to it.
+    pub fn set_delivery_system_checked(&self, system: FeDeliverySystem) -> Result<(), DtvError> {
+        self.set_delivery_system(system)?;
+
+        let mut active = ActiveDeliverySystem::query();
+        self.properties(&mut [active.desc()])?;
+        let active = active.retrieve()?.0;
+        if active != system {
+            return Err(DtvError::DeliverySystemRejected {
+                requested: system,
+                active,
+            });
+        }
+        Ok(())
+    }
+
+    /// Resets the frontend to idle by sending a single ``DTV_CLEAR``, without closing the device.
+    ///
+    /// The last tune is forgotten, so [Frontend::with_reinit_recovery] does not bring it back.
+    pub fn clear(&self) -> Result<(), PropertyError> {
+        self.set_properties(&mut [Clear {}.property()])?;
+        self.last_tune().properties.clear();
+        Ok(())
+    }
+
+    /// Tunes from a clean state: sends ``DTV_CLEAR``, then given properties, then ``DTV_TUNE``, in a single batch.
+    ///
+    /// Without ``DTV_CLEAR``, properties from the previous tune stay in the frontend cache. Switching delivery system
+    /// (DVB-T2 to DVB-T for instance) then leaks stale parameters such as the stream ID into the new tune,
+    /// which is the most common reason for a tune that should work but does not lock.
+    ///
+    /// ``DTV_CLEAR`` and ``DTV_TUNE`` properties already in `properties` are skipped, so built requests can be passed as-is.
+    pub fn retune(&self, properties: &[DtvProperty]) -> Result<(), PropertyError> {
+        let mut batch = Vec::with_capacity(properties.len() + 2);
+        batch.push(Clear {}.property());
+        batch.extend(properties.iter().copied().filter(|property| {
+            property.cmd != Command::DTV_CLEAR as u32 && property.cmd != Command::DTV_TUNE as u32
+        }));
+        batch.push(Tune {}.property());
+        self.set_properties(&mut batch)
+    }
+
+    /// Legacy: tunes with the pre-property API, see [set_frontend]. Only for drivers that do not support properties.
+    pub fn legacy_set_frontend(&self, params: &DvbFrontendParameters) -> Result<(), Errno> {
+        set_frontend(self.fd.as_fd(), params)
+    }
+
+    /// Legacy: reads the current parameters with the pre-property API, see [get_frontend].
+    pub fn legacy_get_frontend(&self) -> Result<DvbFrontendParameters, Errno> {
+        get_frontend(self.fd.as_fd())
+    }
+
+    /// Sends the properties of a tune request, then reads status until the frontend locks or `timeout` expires.
+    ///
+    /// Also returns early if the frontend reports it timed out. The last read status is returned in all cases,
+    /// check [FeStatus::has_lock] to know if tuning succeeded.
+    pub fn tune_and_wait(
+        &self,
+        properties: &mut [DtvProperty],
+        timeout: Duration,
+    ) -> Result<FeStatus, DtvError> {
+        self.set_properties(properties)?;
+
+        let start = Instant::now();
+        loop {
+            let status = self.status().map_err(DtvError::ReadStatus)?;
+            if status.has_lock() || status.timed_out() {
+                return Ok(status);
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Ok(status);
+            }
+            sleep(LOCK_POLL_INTERVAL.min(timeout - elapsed));
+        }
+    }
+
+    /// Async version of the waiting part of [Frontend::tune_and_wait], to call after sending a tune request.
+    ///
+    /// Status is read again whenever the frontend reports an event, and at least every poll interval for drivers that send none.
+    /// Pending frontend events are consumed. If the device cannot be registered with the tokio reactor,
+    /// only the timer is used. Must be called from within a tokio runtime.
+    ///
+    /// The last read status is returned in all cases, check [FeStatus::has_lock] to know if tuning succeeded.
+    #[cfg(feature = "tokio")]
+    pub async fn wait_lock_async(&self, timeout: Duration) -> Result<FeStatus, DtvError> {
+        let events = tokio::io::unix::AsyncFd::new(self.fd.as_fd()).ok();
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let status = self.status().map_err(DtvError::ReadStatus)?;
+            let now = tokio::time::Instant::now();
+            if status.has_lock() || status.timed_out() || now >= deadline {
+                return Ok(status);
+            }
+
+            let wait = LOCK_POLL_INTERVAL.min(deadline - now);
+            let Some(events) = &events else {
+                tokio::time::sleep(wait).await;
+                continue;
+            };
+            if let Ok(Ok(mut guard)) = tokio::time::timeout(wait, events.readable()).await {
+                // Readiness may be stale, only read events that are really there so that a blocking frontend never blocks here
+                while wait_readable(self.fd.as_fd(), Duration::ZERO).unwrap_or(false) {
+                    if get_event(self.fd.as_fd()).is_err_and(|e| e != Errno::EOVERFLOW) {
+                        break;
+                    }
+                }
+                guard.clear_ready();
+            }
+        }
+    }
+
+    /// Waits for the next frontend event, such as a status change after tuning, for up to `timeout`.
+    pub fn wait_for_event(&self, timeout: Duration) -> Result<DvbFrontendEvent, WaitError> {
+        if !wait_readable(self.fd.as_fd(), timeout).map_err(WaitError::Poll)? {
+            return Err(WaitError::Timeout);
+        }
+
+        get_event(self.fd.as_fd()).map_err(|e| match e {
+            Errno::EOVERFLOW => WaitError::Overflow,
+            e => WaitError::GetEvent(e),
+        })
+    }
+
+    /// Sets tune mode flags, such as [FE_TUNE_MODE_ONESHOT](crate::frontend::data::FE_TUNE_MODE_ONESHOT).
+    pub fn set_frontend_tune_mode(&self, flags: u32) -> Result<(), Errno> {
+        set_frontend_tune_mode(self.fd.as_fd(), flags)
+    }
+
+    /// Sends a DiSEqC command to the antenna subsystem.
+    pub fn diseqc_send_master_cmd(&self, cmd: &DvbDiseqcMasterCmd) -> Result<(), Errno> {
+        diseqc_send_master_cmd(self.fd.as_fd(), cmd)?;
+        self.last_tune().record_sec(SecStep::Diseqc(*cmd));
+        Ok(())
+    }
+
+    /// Sends a 22kHz tone burst (mini DiSEqC), used by simple A/B switches.
+    pub fn diseqc_send_burst(&self, burst: SecMiniCmd) -> Result<(), Errno> {
+        diseqc_send_burst(self.fd.as_fd(), burst)?;
+        self.last_tune().record_sec(SecStep::Burst(burst));
+        Ok(())
+    }
+
+    /// Runs a DiSEqC sequence, waiting between steps.
+    ///
+    /// After each step, its [standard delay](DiseqcStep::standard_delay) is observed unless the next step is an explicit [DiseqcStep::Delay].
+    /// A typical sequence is voltage, master command, burst, then tone: with standard delays, that is
+    /// voltage → 15ms → command → 54ms → burst → 15ms → tone → 15ms.
+    pub fn run_diseqc_sequence(&self, steps: &[DiseqcStep]) -> Result<(), Errno> {
+        for (i, step) in steps.iter().enumerate() {
+            match *step {
+                DiseqcStep::Voltage(voltage) => self.set_voltage(voltage)?,
+                DiseqcStep::Tone(tone) => self.set_tone(tone)?,
+                DiseqcStep::MasterCmd(cmd) => self.diseqc_send_master_cmd(&cmd)?,
+                DiseqcStep::Burst(burst) => self.diseqc_send_burst(burst)?,
+                DiseqcStep::Delay(delay) => sleep(delay),
+            }
+            if !matches!(steps.get(i + 1), Some(DiseqcStep::Delay(_))) {
+                sleep(step.standard_delay());
+            }
+        }
+        Ok(())
+    }
+
+    /// Enables or disables the continuous 22kHz tone, used to select the LNB band.
+    pub fn set_tone(&self, tone: SecToneMode) -> Result<(), Errno> {
+        set_tone(self.fd.as_fd(), tone)?;
+        self.last_tune().record_sec(SecStep::Tone(tone));
+        Ok(())
+    }
+
+    /// Sets the DC voltage fed to the LNB, used to select polarization.
+    pub fn set_voltage(&self, voltage: SecVoltage) -> Result<(), Errno> {
+        set_voltage(self.fd.as_fd(), voltage)?;
+        self.last_tune().record_sec(SecStep::Voltage(voltage));
+        Ok(())
+    }
+
+    /// Like [Frontend::monitor], but re-applies the last tune whenever the frontend reports it was reinitialized.
+    ///
+    /// The last tune is the last batch of properties containing ``DTV_TUNE`` sent through this handle,
+    /// along with the voltage, tone and DiSEqC commands sent before it. After a USB glitch for instance,
+    /// the frontend loses all of these and would otherwise stay untuned.
+    pub fn with_reinit_recovery(
+        &self,
+        interval: Duration,
+    ) -> impl Iterator<Item = Result<SignalSnapshot, DtvError>> + '_ {
+        self.monitor(interval).map(|snapshot| {
+            let snapshot = snapshot?;
+            if snapshot.status.reinit() {
+                self.reapply_last_tune()?;
+            }
+            Ok(snapshot)
+        })
+    }
+
+    /// Sends the last tune again, with its voltage, tone and DiSEqC setup. Returns false if nothing was tuned yet.
+    pub fn reapply_last_tune(&self) -> Result<bool, DtvError> {
+        let (sec, mut properties) = {
+            let last_tune = self.last_tune();
+            (last_tune.sec.clone(), last_tune.properties.clone())
+        };
+        if properties.is_empty() {
+            return Ok(false);
+        }
+
+        for step in sec {
+            match step {
+                SecStep::Voltage(voltage) => set_voltage(self.fd.as_fd(), voltage),
+                SecStep::Tone(tone) => set_tone(self.fd.as_fd(), tone),
+                SecStep::Diseqc(cmd) => diseqc_send_master_cmd(self.fd.as_fd(), &cmd),
+                SecStep::Burst(burst) => diseqc_send_burst(self.fd.as_fd(), burst),
+            }
+            .map_err(DtvError::Sec)?;
+        }
+        get_set_properties_raw(self.fd.as_fd(), true, &mut properties)?;
+        Ok(true)
+    }
+
+    fn last_tune(&self) -> MutexGuard<'_, LastTune> {
+        self.last_tune
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// One step of antenna setup, recorded to be replayed after a reinit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SecStep {
+    Voltage(SecVoltage),
+    Tone(SecToneMode),
+    Diseqc(DvbDiseqcMasterCmd),
+    Burst(SecMiniCmd),
+}
+
+impl SecStep {
+    /// Whether this step makes an earlier one pointless to replay: a newer voltage, tone or burst,
+    /// or a DiSEqC command with the same address and command byte (switch input, positioner angle...).
+    fn supersedes(&self, earlier: &SecStep) -> bool {
+        match (self, earlier) {
+            (SecStep::Voltage(_), SecStep::Voltage(_))
+            | (SecStep::Tone(_), SecStep::Tone(_))
+            | (SecStep::Burst(_), SecStep::Burst(_)) => true,
+            (SecStep::Diseqc(cmd), SecStep::Diseqc(earlier)) => cmd.msg[1..3] == earlier.msg[1..3],
+            _ => false,
+        }
+    }
+}
+
+/// Adds a step to an antenna setup, dropping the earlier steps it supersedes.
+fn merge_sec(steps: &mut Vec<SecStep>, step: SecStep) {
+    steps.retain(|earlier| !step.supersedes(earlier));
+    steps.push(step);
+}
+
+/// What was last sent to tune the frontend, see [Frontend::with_reinit_recovery].
+#[derive(Debug, Default)]
+struct LastTune {
+    /// Antenna setup sent since the last tune
+    pending_sec: Vec<SecStep>,
+    /// Antenna setup in effect for the last tune, including steps sent before earlier tunes that were not changed since
+    sec: Vec<SecStep>,
+    properties: Vec<DtvProperty>,
+}
+
+// SAFETY: The only pointer a DtvProperty can hold is the reserved one of the buffer variant, which is never dereferenced.
+// Recorded properties are plain data that is only copied back to the kernel, so it can move to another thread with the Frontend.
+unsafe impl Send for LastTune {}
+
+impl LastTune {
+    /// Keeps the antenna setup bounded when it changes many times before a tune, such as while moving a dish.
+    fn record_sec(&mut self, step: SecStep) {
+        merge_sec(&mut self.pending_sec, step);
+    }
+
+    fn tuned(&mut self, properties: &[DtvProperty]) {
+        // Retuning only resends what changed (the polarization...), the rest of the previous setup still applies
+        for step in std::mem::take(&mut self.pending_sec) {
+            merge_sec(&mut self.sec, step);
+        }
+        self.properties = properties.to_vec();
+    }
+}
+
+/// All statistics of a frontend, see [Frontend::read_all_stats].
+#[derive(Debug)]
+pub struct Stats {
+    pub strength: SignalStrength,
+    pub cnr: CarrierSignalToNoise,
+    pub pre_error_bits: PreErrorBitCount,
+    pub pre_total_bits: PreTotalBitCount,
+    pub post_error_bits: PostErrorBitCount,
+    pub post_total_bits: PostTotalBitCount,
+    pub error_blocks: ErrorBlockCount,
+    pub total_blocks: TotalBlockCount,
+}
+
+impl Stats {
+    /// Bit Error Rate before the inner code, between 0 and 1. `None` if unavailable or nothing was measured yet.
+    pub fn ber(&self) -> Option<f64> {
+        ber(&self.pre_error_bits, &self.pre_total_bits)
+    }
+
+    /// Bit Error Rate after the inner code, between 0 and 1. `None` if unavailable or nothing was measured yet.
+    pub fn post_ber(&self) -> Option<f64> {
+        ratio(
+            self.post_error_bits.global()?,
+            self.post_total_bits.global()?,
+        )
+    }
+
+    /// Packet (block) Error Rate, between 0 and 1. `None` if unavailable or nothing was measured yet.
+    pub fn per(&self) -> Option<f64> {
+        per(&self.error_blocks, &self.total_blocks)
+    }
+}
+
+/// Status and signal quality at a point in time, see [Frontend::monitor].
+#[derive(Debug)]
+pub struct SignalSnapshot {
+    pub status: FeStatus,
+    pub stats: Stats,
+}
+
+/// A maximum of 0 means the driver did not fill the range.
+fn check_range(parameter: &'static str, value: u32, min: u32, max: u32) -> Result<(), RangeError> {
+    if max == 0 || (min..=max).contains(&value) {
+        return Ok(());
+    }
+    Err(RangeError::OutOfRange {
+        parameter,
+        value,
+        min,
+        max,
+    })
+}
+
+impl AsFd for Frontend {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<OwnedFd> for Frontend {
+    /// The access mode is read back from the file descriptor, so that read-only frontends are detected.
+    fn from(fd: OwnedFd) -> Self {
+        let read_only = fcntl(&fd, FcntlArg::F_GETFL).is_ok_and(|flags| {
+            OFlag::from_bits_retain(flags) & OFlag::O_ACCMODE == OFlag::O_RDONLY
+        });
+        Frontend {
+            fd,
+            read_only,
+            last_tune: Mutex::default(),
+            info: OnceLock::new(),
+        }
+    }
+}
+
+impl From<Frontend> for OwnedFd {
+    fn from(frontend: Frontend) -> Self {
+        frontend.fd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retune_keeps_unchanged_sec_steps() {
+        let switch = DvbDiseqcMasterCmd::new(&[0xE0, 0x10, 0x38, 0xF1]);
+        let mut last_tune = LastTune::default();
+        last_tune.record_sec(SecStep::Voltage(SecVoltage::SEC_VOLTAGE_13));
+        last_tune.record_sec(SecStep::Diseqc(switch));
+        last_tune.record_sec(SecStep::Tone(SecToneMode::SEC_TONE_ON));
+        last_tune.tuned(&[]);
+
+        // Only the polarization changes for the next transponder
+        last_tune.record_sec(SecStep::Voltage(SecVoltage::SEC_VOLTAGE_18));
+        last_tune.tuned(&[]);
+
+        assert!(last_tune.pending_sec.is_empty());
+        assert_eq!(
+            last_tune.sec,
+            [
+                SecStep::Diseqc(switch),
+                SecStep::Tone(SecToneMode::SEC_TONE_ON),
+                SecStep::Voltage(SecVoltage::SEC_VOLTAGE_18),
+            ]
+        );
+    }
+}
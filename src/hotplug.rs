@@ -0,0 +1,144 @@
+//! Notifications when DVB adapters are plugged or unplugged, from kernel uevents
+
+use std::{
+    collections::HashSet,
+    os::fd::{AsFd, AsRawFd as _, BorrowedFd, OwnedFd},
+};
+
+use nix::{
+    errno::Errno,
+    sys::socket::{
+        AddressFamily, MsgFlags, NetlinkAddr, SockFlag, SockProtocol, SockType, bind, recv, socket,
+    },
+};
+
+use crate::devices::{Adapter, adapter_by_id, adapter_ids};
+
+/// Multicast group of the netlink socket on which the kernel sends uevents.
+const KERNEL_UEVENT_GROUP: u32 = 1;
+
+/// Uevents are small, this is the buffer size udev uses as well.
+const UEVENT_BUFFER_SIZE: usize = 8192;
+
+/// A change in the DVB adapters attached to the system.
+#[derive(Debug)]
+pub enum HotplugEvent {
+    /// A new adapter is ready to use, with at least one frontend.
+    Added(Adapter),
+    /// An adapter was removed, with the number it had (as in `/dev/dvb/adapterN`).
+    Removed(String),
+}
+
+/// Listens to kernel uevents and yields [HotplugEvent]s, blocking until an adapter comes or goes.
+///
+/// Adapters are registered one sub-device at a time (demux, dvr, frontend...). An adapter is reported as added
+/// once it has a frontend, and as removed once its last sub-device is gone. Adapters already attached
+/// when the watcher is created are not reported.
+///
+/// The watcher can be polled through [AsFd] to integrate with an event loop.
+#[derive(Debug)]
+pub struct HotplugWatcher {
+    fd: OwnedFd,
+    known: HashSet<String>,
+    buf: Vec<u8>,
+}
+
+impl HotplugWatcher {
+    pub fn new() -> Result<HotplugWatcher, Errno> {
+        let fd = socket(
+            AddressFamily::Netlink,
+            SockType::Datagram,
+            SockFlag::SOCK_CLOEXEC,
+            SockProtocol::NetlinkKObjectUEvent,
+        )?;
+        bind(fd.as_raw_fd(), &NetlinkAddr::new(0, KERNEL_UEVENT_GROUP))?;
+
+        let known = adapter_ids()
+            .into_iter()
+            .filter(|id| adapter_by_id(id).is_some_and(|adapter| has_frontend(&adapter)))
+            .collect();
+
+        Ok(HotplugWatcher {
+            fd,
+            known,
+            buf: vec![0; UEVENT_BUFFER_SIZE],
+        })
+    }
+
+    /// Blocks until the next adapter is added or removed.
+    pub fn next_event(&mut self) -> Result<HotplugEvent, Errno> {
+        loop {
+            let len = match recv(self.fd.as_raw_fd(), &mut self.buf, MsgFlags::empty()) {
+                Err(Errno::EINTR) => continue,
+                result => result?,
+            };
+            let Some((action, adapter_id)) = parse_uevent(&self.buf[..len]) else {
+                continue;
+            };
+
+            match action {
+                "add" if !self.known.contains(&adapter_id) => {
+                    if let Some(adapter) = adapter_by_id(&adapter_id)
+                        && has_frontend(&adapter)
+                    {
+                        self.known.insert(adapter_id);
+                        return Ok(HotplugEvent::Added(adapter));
+                    }
+                }
+                "remove"
+                    if self.known.contains(&adapter_id) && !adapter_ids().contains(&adapter_id) =>
+                {
+                    self.known.remove(&adapter_id);
+                    return Ok(HotplugEvent::Removed(adapter_id));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Iterator for HotplugWatcher {
+    type Item = Result<HotplugEvent, Errno>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_event())
+    }
+}
+
+impl AsFd for HotplugWatcher {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+fn has_frontend(adapter: &Adapter) -> bool {
+    adapter.frontend_count() > 0
+}
+
+/// Extracts the action and the adapter number from a uevent of the dvb subsystem.
+///
+/// A uevent is a header (`add@/devices/...`) followed by `KEY=value` pairs, all separated by NUL bytes.
+/// The device path ends with the name of the sub-device, such as `dvb0.frontend0`, as in `/sys/class/dvb`.
+fn parse_uevent(message: &[u8]) -> Option<(&str, String)> {
+    let mut action = None;
+    let mut devpath = None;
+    let mut subsystem = None;
+    for field in message.split(|&b| b == 0).skip(1) {
+        let Ok(field) = std::str::from_utf8(field) else {
+            continue;
+        };
+        match field.split_once('=') {
+            Some(("ACTION", value)) => action = Some(value),
+            Some(("DEVPATH", value)) => devpath = Some(value),
+            Some(("SUBSYSTEM", value)) => subsystem = Some(value),
+            _ => {}
+        }
+    }
+    if subsystem? != "dvb" {
+        return None;
+    }
+
+    let (device, _) = devpath?.rsplit('/').next()?.split_once('.')?;
+    let adapter_id = device.strip_prefix("dvb")?;
+    Some((action?, adapter_id.to_string()))
+}
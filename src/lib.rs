@@ -1,7 +1,30 @@
+//! Linux DVB API: raw ioctls, safe wrappers and helpers.
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std` and only exposes the plain
+//! data types shared with the kernel (`data` and `property` modules), for projects doing their own ioctls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod ca;
+pub mod channels;
+#[cfg(feature = "std")]
+pub mod config;
 pub mod demux;
+#[cfg(feature = "std")]
 pub mod devices;
+#[cfg(feature = "std")]
+pub mod dvr;
+#[cfg(feature = "std")]
 pub mod error;
+#[cfg(feature = "std")]
+mod fd;
 pub mod frontend;
+#[cfg(feature = "hotplug")]
+pub mod hotplug;
+pub mod net;
+#[cfg(feature = "std")]
+pub mod scan;
+pub mod ts;
 
 /// For all IOCTLs related to DVB
 pub const IOCTL_TYPE: u8 = b'o';
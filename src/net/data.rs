@@ -0,0 +1,18 @@
+/// Multi Protocol Encapsulation
+pub const DVB_NET_FEEDTYPE_MPE: u8 = 0;
+/// Ultra Lightweight Encapsulation
+pub const DVB_NET_FEEDTYPE_ULE: u8 = 1;
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/net-types.html#c.dvb_net_if))
+///
+/// describes a DVB network interface
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DvbNetIf {
+    /// Packet ID (PID) of the MPEG-TS that contains data
+    pub pid: u16,
+    /// number of the Digital TV interface.
+    pub if_num: u16,
+    /// Encapsulation type of the feed. It can be: [DVB_NET_FEEDTYPE_MPE] for MPE encoding or [DVB_NET_FEEDTYPE_ULE] for ULE encoding.
+    pub feedtype: u8,
+}
@@ -0,0 +1,53 @@
+use std::{
+    ffi::c_int,
+    os::fd::{AsRawFd as _, BorrowedFd},
+};
+
+use nix::errno::Errno;
+
+use crate::net::{
+    data::DvbNetIf,
+    ioctl::{net_add_if, net_get_if, net_remove_if},
+};
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/net-add-if.html#description))
+///
+/// The NET_ADD_IF ioctl system call selects the Packet ID (PID) that contains a TCP/IP traffic,
+/// the type of encapsulation to be used (MPE or ULE) and the interface number for the new interface to be created.
+/// When the system call successfully returns, a new virtual network interface is created.
+///
+/// Returns the number of the created interface.
+pub fn add_if(fd: BorrowedFd, pid: u16, feedtype: u8) -> Result<u16, Errno> {
+    let mut net_if = DvbNetIf {
+        pid,
+        if_num: 0,
+        feedtype,
+    };
+    // SAFETY: FD is always valid, DvbNetIf is C-compatible and always valid. There should be no conditions or unhandled side-effects.
+    unsafe { net_add_if(fd.as_raw_fd(), &mut net_if) }?;
+    Ok(net_if.if_num)
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/net-remove-if.html#description))
+///
+/// The NET_REMOVE_IF ioctl deletes an interface previously created via NET_ADD_IF.
+pub fn remove_if(fd: BorrowedFd, if_num: u16) -> Result<(), Errno> {
+    // SAFETY: FD is always valid, and the interface number is passed by value. There should be no conditions or unhandled side-effects.
+    unsafe { net_remove_if(fd.as_raw_fd(), if_num as c_int) }?;
+    Ok(())
+}
+
+/// (taken from [official docs](https://www.linuxtv.org/downloads/v4l-dvb-apis-new/userspace-api/dvb/net-get-if.html#description))
+///
+/// The NET_GET_IF ioctl uses the interface number given by the if_num field and fills the content of struct dvb_net_if
+/// with the packet ID and encapsulation type used on such interface.
+/// If the interface was not created yet with NET_ADD_IF, it will return -1 and fill the errno with EINVAL error code.
+pub fn get_if(fd: BorrowedFd, if_num: u16) -> Result<DvbNetIf, Errno> {
+    let mut net_if = DvbNetIf {
+        if_num,
+        ..Default::default()
+    };
+    // SAFETY: FD is always valid, DvbNetIf is C-compatible and always valid. There should be no conditions or unhandled side-effects.
+    unsafe { net_get_if(fd.as_raw_fd(), &mut net_if) }?;
+    Ok(net_if)
+}
@@ -0,0 +1,12 @@
+use nix::{ioctl_readwrite, ioctl_write_int_bad, request_code_none};
+
+use crate::{IOCTL_TYPE, net::data::DvbNetIf};
+
+const NET_ADD_IF: u8 = 52;
+ioctl_readwrite!(net_add_if, IOCTL_TYPE, NET_ADD_IF, DvbNetIf);
+
+const NET_REMOVE_IF: u8 = 53;
+ioctl_write_int_bad!(net_remove_if, request_code_none!(IOCTL_TYPE, NET_REMOVE_IF)); // Takes the interface number by value
+
+const NET_GET_IF: u8 = 54;
+ioctl_readwrite!(net_get_if, IOCTL_TYPE, NET_GET_IF, DvbNetIf);
@@ -0,0 +1,5 @@
+pub mod data;
+#[cfg(feature = "std")]
+pub mod functions;
+#[cfg(feature = "std")]
+pub mod ioctl;
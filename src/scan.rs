@@ -0,0 +1,138 @@
+//! Scanning for channels without a channel list
+
+use std::time::Duration;
+
+use crate::{
+    error::{DtvError, ScanError},
+    frontend::{
+        data::{FE_TUNE_MODE_ONESHOT, FeStatus},
+        queries::{
+            get::{Bandwidth, Frequency, Modulation, PropertyQuery as _},
+            set::DvbtTuneRequest,
+        },
+        wrapper::Frontend,
+    },
+};
+
+/// A frequency where the frontend locked during a sweep.
+#[derive(Debug)]
+pub struct SweepResult {
+    /// Frequency that was tuned, in Hz.
+    pub frequency: u32,
+    pub status: FeStatus,
+    /// Parameters as read back from the frontend after lock.
+    pub actual_frequency: Frequency,
+    pub modulation: Modulation,
+    pub bandwidth: Bandwidth,
+}
+
+/// Iterator over frequencies of a sweep where the frontend locked, see [sweep].
+pub struct Sweep<'a> {
+    frontend: &'a Frontend,
+    next_frequency: u64,
+    end: u64,
+    step: u64,
+    timeout: Duration,
+    tune_mode_set: bool,
+}
+
+/// Tunes to every frequency from `start_hz` to `end_hz` (included) by `step_hz`, with all DVB-T parameters on AUTO,
+/// and yields the frequencies where lock was obtained within `timeout`.
+///
+/// The frontend is set to oneshot tune mode so that it does not wander away from the requested frequency.
+///
+/// Panics if `step_hz` is 0.
+pub fn sweep(
+    frontend: &Frontend,
+    start_hz: u32,
+    end_hz: u32,
+    step_hz: u32,
+    timeout: Duration,
+) -> Sweep<'_> {
+    assert!(step_hz > 0, "sweep step must not be 0");
+
+    Sweep {
+        frontend,
+        next_frequency: start_hz as u64,
+        end: end_hz as u64,
+        step: step_hz as u64,
+        timeout,
+        tune_mode_set: false,
+    }
+}
+
+impl Sweep<'_> {
+    fn read_back(&self, frequency: u32, status: FeStatus) -> Result<SweepResult, DtvError> {
+        let mut actual_frequency = Frequency::query();
+        let mut modulation = Modulation::query();
+        let mut bandwidth = Bandwidth::query();
+        self.frontend.properties(&mut [
+            actual_frequency.desc(),
+            modulation.desc(),
+            bandwidth.desc(),
+        ])?;
+
+        Ok(SweepResult {
+            frequency,
+            status,
+            actual_frequency: actual_frequency.retrieve()?,
+            modulation: modulation.retrieve()?,
+            bandwidth: bandwidth.retrieve()?,
+        })
+    }
+}
+
+impl Iterator for Sweep<'_> {
+    type Item = Result<SweepResult, ScanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.tune_mode_set {
+            self.tune_mode_set = true;
+            if let Err(e) = self.frontend.set_frontend_tune_mode(FE_TUNE_MODE_ONESHOT) {
+                return Some(Err(ScanError::TuneMode(e)));
+            }
+        }
+
+        while self.next_frequency <= self.end {
+            let frequency = self.next_frequency as u32;
+            self.next_frequency += self.step;
+
+            let mut properties = DvbtTuneRequest::new()
+                .frequency(frequency)
+                .build()
+                .expect("frequency is always set");
+            let status = match self.frontend.tune_and_wait(&mut properties, self.timeout) {
+                Ok(status) => status,
+                Err(e) => return Some(Err(e.into())),
+            };
+            if status.has_lock() {
+                return Some(self.read_back(frequency, status).map_err(ScanError::from));
+            }
+        }
+
+        None
+    }
+}
+
+/// Finds the PLPs (Physical Layer Pipes) of a DVB-T2 multiplex, by tuning to each id in `0..count` in turn.
+///
+/// `base_request` must be a DVB-T2 request for the multiplex, its stream id is replaced by each candidate.
+/// Ids where the frontend gets sync within `timeout` are returned.
+///
+/// Some drivers lock on the multiplex whatever the requested PLP, in which case every candidate is returned.
+pub fn plp_ids(
+    frontend: &Frontend,
+    base_request: &DvbtTuneRequest,
+    count: u32,
+    timeout: Duration,
+) -> Result<Vec<u32>, ScanError> {
+    let mut ids = Vec::new();
+    for id in 0..count {
+        let mut properties = base_request.clone().stream_id(id).build()?;
+        let status = frontend.tune_and_wait(&mut properties, timeout)?;
+        if status.has_sync() {
+            ids.push(id);
+        }
+    }
+    Ok(ids)
+}
@@ -0,0 +1,70 @@
+//! Typed view over transport stream packets
+
+use core::fmt;
+
+/// Size of a transport stream packet.
+pub const TS_PACKET_SIZE: usize = 188;
+
+/// First byte of every transport stream packet.
+pub const TS_SYNC_BYTE: u8 = 0x47;
+
+/// A transport stream packet, as yielded by [Packets](crate::dvr::wrapper::Packets) or read from a recording.
+///
+/// Only the 4-byte header is decoded. Debug output shows the header fields rather than the 188 bytes.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct TsPacket(pub [u8; TS_PACKET_SIZE]);
+
+impl TsPacket {
+    /// Whether the packet starts with [TS_SYNC_BYTE]. If not, the stream lost its alignment.
+    pub fn sync_ok(&self) -> bool {
+        self.0[0] == TS_SYNC_BYTE
+    }
+
+    /// Set by the demodulator when the packet has uncorrectable errors.
+    pub fn transport_error(&self) -> bool {
+        self.0[1] & 0x80 != 0
+    }
+
+    /// Whether a PES packet or a section starts in this packet.
+    pub fn payload_unit_start(&self) -> bool {
+        self.0[1] & 0x40 != 0
+    }
+
+    pub fn pid(&self) -> u16 {
+        u16::from_be_bytes([self.0[1] & 0x1F, self.0[2]])
+    }
+
+    /// Whether the packet carries a payload, after the adaptation field if any.
+    pub fn has_payload(&self) -> bool {
+        self.0[3] & 0x10 != 0
+    }
+
+    /// Counter from 0 to 15, incremented on each packet of the same PID that has a payload.
+    ///
+    /// A gap means packets were lost. Packets without payload, and one duplicate packet, keep the same value.
+    pub fn continuity_counter(&self) -> u8 {
+        self.0[3] & 0x0F
+    }
+
+    pub fn as_bytes(&self) -> &[u8; TS_PACKET_SIZE] {
+        &self.0
+    }
+}
+
+impl From<[u8; TS_PACKET_SIZE]> for TsPacket {
+    fn from(bytes: [u8; TS_PACKET_SIZE]) -> Self {
+        TsPacket(bytes)
+    }
+}
+
+impl fmt::Debug for TsPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TsPacket")
+            .field("sync_ok", &self.sync_ok())
+            .field("pid", &format_args!("{:#06x}", self.pid()))
+            .field("transport_error", &self.transport_error())
+            .field("payload_unit_start", &self.payload_unit_start())
+            .field("continuity_counter", &self.continuity_counter())
+            .finish()
+    }
+}
@@ -0,0 +1,12 @@
+use rdvb_os_linux::channels::{Region, uhf_center_hz};
+
+#[test]
+fn uhf_channels() {
+    assert_eq!(uhf_center_hz(21, Region::Europe), Some(474_000_000));
+    assert_eq!(uhf_center_hz(69, Region::Europe), Some(858_000_000));
+    assert_eq!(uhf_center_hz(14, Region::NorthAmerica), Some(473_000_000));
+    assert_eq!(uhf_center_hz(13, Region::Japan), Some(473_142_857));
+    assert_eq!(uhf_center_hz(14, Region::Brazil), Some(473_142_857));
+    assert_eq!(uhf_center_hz(70, Region::Europe), None);
+    assert_eq!(uhf_center_hz(13, Region::NorthAmerica), None);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use rdvb_os_linux::devices::list_adapters_from;
+
+/// Fake sysfs tree, removed when dropped so that a failing test does not leave it behind.
+struct Fixture(PathBuf);
+
+impl Fixture {
+    fn new() -> Fixture {
+        let base = std::env::temp_dir().join(format!("rdvb-sysfs.{}", std::process::id()));
+        // Left over by a previous run which reused the same process id
+        let _ = fs::remove_dir_all(&base);
+        Fixture(base)
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn sub_device(base: &Path, name: &str, attributes: &[(&str, &str)]) {
+    let device = base.join(name).join("device");
+    fs::create_dir_all(&device).unwrap();
+    for (attribute, value) in attributes {
+        fs::write(device.join(attribute), format!("{value}\n")).unwrap();
+    }
+}
+
+#[test]
+fn adapters_from_fixture() {
+    let fixture = Fixture::new();
+    let base = &fixture.0;
+    let dual = [("product", "Dual Tuner"), ("serial", "ABC123")];
+    for name in [
+        "dvb0.frontend0",
+        "dvb0.frontend1",
+        "dvb0.demux0",
+        "dvb0.dvr0",
+    ] {
+        sub_device(base, name, &dual);
+    }
+    sub_device(base, "dvb1.frontend0", &[]);
+
+    let mut adapters = list_adapters_from(base).unwrap();
+    adapters.sort_by(|a, b| a.adapter_id().cmp(b.adapter_id()));
+
+    assert_eq!(adapters.len(), 2);
+    assert_eq!(adapters[0].frontend_count(), 2);
+    assert_eq!(adapters[0].demux_count(), 1);
+    assert_eq!(adapters[0].net_count(), 0);
+    assert_eq!(
+        adapters[0].to_string(),
+        "adapter0 (Dual Tuner, serial ABC123)"
+    );
+    assert_eq!(adapters[1].serial(), "");
+    assert_eq!(adapters[1].to_string(), "adapter1");
+}
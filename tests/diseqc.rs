@@ -0,0 +1,32 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::frontend::diseqc::positioner::{usals_angle, usals_goto};
+
+#[test]
+fn usals_due_south() {
+    assert_eq!(usals_angle(48.85, 13.0, 13.0), 0.0);
+    assert_eq!(
+        usals_goto(48.85, 13.0, 13.0).bytes(),
+        &[0xE0, 0x31, 0x6E, 0xE0, 0x00]
+    );
+}
+
+#[test]
+fn usals_astra_2_from_london() {
+    // Astra 2 (28.2°E) from 51.5°N 0°E needs a rotation of about 31° east
+    let angle = usals_angle(51.5, 0.0, 28.2);
+    assert!((angle - 30.98).abs() < 0.01, "got {}", angle);
+    assert_eq!(
+        usals_goto(51.5, 0.0, 28.2).bytes(),
+        &[0xE0, 0x31, 0x6E, 0xE1, 0xF0]
+    );
+}
+
+#[test]
+fn usals_west() {
+    // 30°W from 51.5°N 0°E, about 32.9° west
+    assert_eq!(
+        usals_goto(51.5, 0.0, -30.0).bytes(),
+        &[0xE0, 0x31, 0x6E, 0xD2, 0x0F]
+    );
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::{
+    config::dvbv5::parse_channels,
+    error::Dvbv5ParseError,
+    frontend::{data::FeDeliverySystem, property::Command},
+};
+
+#[test]
+fn terrestrial_channel() {
+    let channels = parse_channels(
+        "[France 2]
+        FREQUENCY = 490000000
+        DELIVERY_SYSTEM = DVBT
+        BANDWIDTH_HZ = 8000000
+        SERVICE_ID = 257",
+    )
+    .unwrap();
+
+    let (name, properties) = &channels[0];
+    assert_eq!(name, "France 2");
+    let commands: Vec<u32> = properties.iter().map(|p| p.cmd).collect();
+    assert_eq!(
+        commands,
+        [
+            Command::DTV_DELIVERY_SYSTEM as u32,
+            Command::DTV_FREQUENCY as u32,
+            Command::DTV_BANDWIDTH_HZ as u32,
+            Command::DTV_TUNE as u32,
+        ]
+    );
+}
+
+#[test]
+fn satellite_channel_is_rejected() {
+    let error = parse_channels(
+        "[Das Erste HD]
+        DELIVERY_SYSTEM = DVBS2
+        FREQUENCY = 11494000
+        POLARIZATION = HORIZONTAL
+        LNB = UNIVERSAL",
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        Dvbv5ParseError::Satellite {
+            ref channel,
+            system: FeDeliverySystem::DVBS2,
+        } if channel == "Das Erste HD"
+    ));
+}
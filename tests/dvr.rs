@@ -0,0 +1,28 @@
+#![cfg(feature = "std")]
+
+use std::{fs::File, io::Write as _, os::fd::OwnedFd};
+
+use rdvb_os_linux::dvr::wrapper::{Dvr, TS_PACKET_SIZE, TS_SYNC_BYTE};
+
+fn packet(id: u8) -> [u8; TS_PACKET_SIZE] {
+    let mut packet = [id; TS_PACKET_SIZE];
+    packet[0] = TS_SYNC_BYTE;
+    packet
+}
+
+#[test]
+fn packets_resync_on_sync_byte() {
+    let path = std::env::temp_dir().join(format!("rdvb-dvr-test-{}", std::process::id()));
+    let mut stream = vec![0x00, TS_SYNC_BYTE, 0x12];
+    for id in 1..=3 {
+        stream.extend_from_slice(&packet(id));
+    }
+    stream.extend_from_slice(&[0xFF; 10]);
+    File::create(&path).unwrap().write_all(&stream).unwrap();
+
+    let dvr = Dvr::from(OwnedFd::from(File::open(&path).unwrap()));
+    let ids: Vec<u8> = dvr.packets().map(|packet| packet.unwrap()[1]).collect();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(ids, [1, 2, 3]);
+}
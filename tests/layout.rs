@@ -0,0 +1,95 @@
+//! Layouts of the structures shared with the kernel, which must match the C headers exactly.
+
+use std::mem::{align_of, offset_of, size_of};
+
+use rdvb_os_linux::{
+    demux::data::DmxStc,
+    frontend::{
+        data::{
+            DvbDiseqcMasterCmd, DvbFrontendEvent, DvbFrontendInfo, DvbFrontendParameters,
+            FeCodeRate, FeGuardInterval, FeModulation, FeSpectralInversion, FeTransmitMode,
+        },
+        property::{DtvFeStats, DtvProperties, DtvProperty, DtvStats},
+    },
+};
+
+#[test]
+fn stats() {
+    assert_eq!(size_of::<DtvStats>(), 9);
+    assert_eq!(offset_of!(DtvStats, scale), 0);
+    assert_eq!(offset_of!(DtvStats, value), 1);
+    assert_eq!(align_of::<DtvStats>(), 1);
+
+    assert_eq!(size_of::<DtvFeStats>(), 37);
+    assert_eq!(offset_of!(DtvFeStats, len), 0);
+    assert_eq!(offset_of!(DtvFeStats, stat), 1);
+    assert_eq!(align_of::<DtvFeStats>(), 1);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn property() {
+    assert_eq!(size_of::<DtvProperty>(), 76);
+    assert_eq!(offset_of!(DtvProperty, cmd), 0);
+    assert_eq!(offset_of!(DtvProperty, reserved), 4);
+    assert_eq!(offset_of!(DtvProperty, u), 16);
+    assert_eq!(offset_of!(DtvProperty, result), 72);
+    assert_eq!(align_of::<DtvProperty>(), 1);
+
+    assert_eq!(size_of::<DtvProperties>(), 16);
+    assert_eq!(offset_of!(DtvProperties, props), 8);
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+fn property() {
+    assert_eq!(size_of::<DtvProperty>(), 72);
+    assert_eq!(offset_of!(DtvProperty, result), 68);
+    assert_eq!(size_of::<DtvProperties>(), 8);
+}
+
+#[test]
+fn frontend_info() {
+    assert_eq!(size_of::<DvbFrontendInfo>(), 168);
+    assert_eq!(offset_of!(DvbFrontendInfo, type_), 128);
+    assert_eq!(offset_of!(DvbFrontendInfo, notifier_delay), 160);
+    assert_eq!(offset_of!(DvbFrontendInfo, caps), 164);
+    assert_eq!(align_of::<DvbFrontendInfo>(), 4);
+}
+
+#[test]
+fn legacy_parameters() {
+    assert_eq!(size_of::<DvbFrontendParameters>(), 36);
+    assert_eq!(offset_of!(DvbFrontendParameters, u), 8);
+    assert_eq!(size_of::<DvbFrontendEvent>(), 40);
+    assert_eq!(offset_of!(DvbFrontendEvent, parameters), 4);
+}
+
+#[test]
+fn small_structs() {
+    assert_eq!(size_of::<DvbDiseqcMasterCmd>(), 7);
+    assert_eq!(offset_of!(DvbDiseqcMasterCmd, msg_len), 6);
+
+    assert_eq!(size_of::<DmxStc>(), 16);
+    assert_eq!(offset_of!(DmxStc, stc), 8);
+    assert_eq!(align_of::<DmxStc>(), 8);
+}
+
+#[test]
+fn enum_values() {
+    assert_eq!(FeModulation::QPSK as u32, 0);
+    assert_eq!(FeModulation::QAM_256 as u32, 5);
+    assert_eq!(FeModulation::VSB_8 as u32, 7);
+    assert_eq!(FeModulation::APSK_64_L as u32, 20);
+
+    assert_eq!(FeSpectralInversion::INVERSION_AUTO as u32, 2);
+
+    assert_eq!(FeGuardInterval::GUARD_INTERVAL_AUTO as u32, 4);
+    assert_eq!(FeGuardInterval::GUARD_INTERVAL_1_64 as u32, 11);
+
+    assert_eq!(FeTransmitMode::TRANSMISSION_MODE_AUTO as u32, 2);
+    assert_eq!(FeTransmitMode::TRANSMISSION_MODE_C3780 as u32, 8);
+
+    assert_eq!(FeCodeRate::FEC_AUTO as u32, 9);
+    assert_eq!(FeCodeRate::FEC_7_15 as u32, 32);
+}
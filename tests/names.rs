@@ -0,0 +1,74 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::frontend::data::{FeDeliverySystem, FeModulation};
+
+#[test]
+fn delivery_system_spellings() {
+    for name in ["DVBT2", "DVB-T2", "dvb-t2", "dvb_t2"] {
+        assert_eq!(
+            name.parse::<FeDeliverySystem>().unwrap(),
+            FeDeliverySystem::DVBT2
+        );
+    }
+    assert!("DVB-X".parse::<FeDeliverySystem>().is_err());
+}
+
+#[test]
+fn delivery_system_round_trip() {
+    for system in [
+        FeDeliverySystem::DVBC_ANNEX_A,
+        FeDeliverySystem::DVBC_ANNEX_C,
+        FeDeliverySystem::DVBS2,
+        FeDeliverySystem::ATSCMH,
+        FeDeliverySystem::ISDBT,
+    ] {
+        assert_eq!(
+            system.to_string().parse::<FeDeliverySystem>().unwrap(),
+            system
+        );
+    }
+}
+
+#[test]
+fn modulation_spellings() {
+    for name in ["QAM256", "QAM_256", "256QAM", "qam/256"] {
+        assert!(matches!(name.parse(), Ok(FeModulation::QAM_256)));
+    }
+    assert!(matches!("8VSB".parse(), Ok(FeModulation::VSB_8)));
+    assert!(matches!("QPSK".parse(), Ok(FeModulation::QPSK)));
+    assert!(matches!("APSK_32_L".parse(), Ok(FeModulation::APSK_32_L)));
+    assert!("QAM257".parse::<FeModulation>().is_err());
+}
+
+#[test]
+fn modulation_round_trip() {
+    for name in ["QPSK", "QAM_AUTO", "8PSK", "QAM_4_NR", "64APSK-L"] {
+        let modulation: FeModulation = name.parse().unwrap();
+        let again: FeModulation = modulation.to_string().parse().unwrap();
+        assert_eq!(modulation as u32, again as u32);
+    }
+}
+
+#[test]
+fn dvbv5_spellings() {
+    for (name, system) in [
+        ("DVBC/ANNEX_A", FeDeliverySystem::DVBC_ANNEX_A),
+        ("DVBC/ANNEX_B", FeDeliverySystem::DVBC_ANNEX_B),
+        ("DVBC/ANNEX_C", FeDeliverySystem::DVBC_ANNEX_C),
+        ("ATSCMH", FeDeliverySystem::ATSCMH),
+    ] {
+        assert_eq!(name.parse::<FeDeliverySystem>().unwrap(), system);
+    }
+    for (name, modulation) in [
+        ("QAM/AUTO", FeModulation::QAM_AUTO),
+        ("VSB/8", FeModulation::VSB_8),
+        ("PSK/8", FeModulation::PSK_8),
+        ("QAM/4_NR", FeModulation::QAM_4_NR),
+        ("APSK/64_L", FeModulation::APSK_64_L),
+    ] {
+        assert_eq!(
+            name.parse::<FeModulation>().unwrap() as u32,
+            modulation as u32
+        );
+    }
+}
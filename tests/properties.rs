@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+use std::{
+    fs::File,
+    os::fd::{AsFd as _, OwnedFd},
+};
+
+use nix::errno::Errno;
+use rdvb_os_linux::{
+    error::PropertyError,
+    frontend::{
+        functions::get_set_properties_raw,
+        property::{Command, DtvProperty},
+        wrapper::Frontend,
+    },
+};
+
+#[test]
+fn too_many_parameters() {
+    let file = File::open("/dev/null").unwrap();
+    let mut properties = vec![DtvProperty::new_empty(Command::DTV_FREQUENCY); 70];
+
+    let error = get_set_properties_raw(file.as_fd(), false, &mut properties).unwrap_err();
+    assert_eq!(error.to_string(), "requested 70 properties, max is 64");
+}
+
+#[test]
+fn raw_properties_on_read_only_frontend() {
+    let frontend = Frontend::from(OwnedFd::from(File::open("/dev/null").unwrap()));
+    let mut properties = [DtvProperty::new_empty(Command::DTV_FREQUENCY)];
+
+    assert!(matches!(
+        frontend.get_raw(&mut properties),
+        Err(PropertyError::GetProperty(Errno::ENOTTY))
+    ));
+    assert!(matches!(
+        frontend.set_raw(&mut properties),
+        Err(PropertyError::ReadOnly)
+    ));
+}
+
+#[test]
+fn debug_decodes_variant() {
+    let delsys = DtvProperty::new_empty(Command::DTV_ENUM_DELSYS);
+    assert_eq!(
+        format!("{delsys:?}"),
+        "DtvProperty { cmd: DTV_ENUM_DELSYS, buffer: [], result: 0 }"
+    );
+
+    let frequency = DtvProperty::new_data(Command::DTV_FREQUENCY, 474_000_000);
+    assert_eq!(
+        format!("{frequency:?}"),
+        "DtvProperty { cmd: DTV_FREQUENCY, data: 474000000, result: 0 }"
+    );
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::{
+    demux::{
+        psi::{ElementaryStream, Pat, Pmt, stream_type},
+        section::crc32_mpeg2,
+    },
+    error::PsiError,
+};
+
+fn with_crc(mut section: Vec<u8>) -> Vec<u8> {
+    let crc = crc32_mpeg2(&section);
+    section.extend_from_slice(&crc.to_be_bytes());
+    section
+}
+
+#[test]
+fn pat() {
+    let section = with_crc(vec![
+        0x00, 0xB0, 0x11, 0x12, 0x34, 0xC1, 0x00, 0x00, // header, transport stream 0x1234
+        0x00, 0x00, 0xE0, 0x10, // network PID 0x10
+        0x00, 0x01, 0xE1, 0x00, // program 1 on PID 0x100
+    ]);
+
+    let pat = Pat::parse(&section).unwrap();
+    assert_eq!(pat.transport_stream_id, 0x1234);
+    assert_eq!(pat.network_pid, Some(0x10));
+    assert_eq!(pat.programs.into_iter().collect::<Vec<_>>(), [(1, 0x100)]);
+}
+
+#[test]
+fn pmt() {
+    let section = with_crc(vec![
+        0x02, 0xB0, 0x1F, 0x00, 0x01, 0xC1, 0x00, 0x00, // header, program 1
+        0xE1, 0x01, 0xF0, 0x00, // PCR PID 0x101, no program descriptors
+        0x1B, 0xE1, 0x01, 0xF0, 0x00, // H.264 on 0x101
+        0x03, 0xE1, 0x02, 0xF0, 0x03, 0x0A, 0x01,
+        0x00, // MPEG-1 audio on 0x102, one descriptor
+        0x03, 0xE1, 0x03, 0xF0, 0x00, // second MPEG-1 audio on 0x103
+    ]);
+
+    let pmt = Pmt::parse(&section).unwrap();
+    assert_eq!(pmt.program_number, 1);
+    assert_eq!(pmt.pcr_pid, 0x101);
+    assert_eq!(pmt.streams.len(), 3);
+    assert_eq!(
+        pmt.streams[1],
+        ElementaryStream {
+            stream_type: stream_type::MPEG1_AUDIO,
+            pid: 0x102
+        }
+    );
+
+    let pids = pmt.pids_by_stream_type();
+    assert_eq!(pids[&stream_type::H264_VIDEO], 0x101);
+    assert_eq!(pids[&stream_type::MPEG1_AUDIO], 0x102);
+}
+
+#[test]
+fn wrong_table() {
+    let section = with_crc(vec![0x00, 0xB0, 0x09, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00]);
+    assert!(matches!(
+        Pmt::parse(&section),
+        Err(PsiError::UnexpectedTable {
+            expected: 0x02,
+            found: 0x00
+        })
+    ));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::{
+    demux::section::{SectionReader, crc32_mpeg2},
+    error::SectionError,
+};
+
+/// PAT with a single program 1 on PMT PID 0x100, CRC32 appended.
+fn pat() -> Vec<u8> {
+    let mut section = vec![
+        0x00, 0xB0, 0x0D, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xE1, 0x00,
+    ];
+    let crc = crc32_mpeg2(&section);
+    section.extend_from_slice(&crc.to_be_bytes());
+    section
+}
+
+#[test]
+fn crc32_check_value() {
+    assert_eq!(crc32_mpeg2(b"123456789"), 0x0376_E6E7);
+    assert_eq!(crc32_mpeg2(&pat()), 0);
+}
+
+#[test]
+fn reassembles_split_sections() {
+    let mut stream = pat();
+    stream.extend(pat());
+    stream.extend([0xFF; 5]);
+
+    let mut reader = SectionReader::new();
+    let mut sections = Vec::new();
+    for chunk in stream.chunks(5) {
+        reader.push(chunk);
+        sections.extend(reader.by_ref().map(Result::unwrap));
+    }
+
+    assert_eq!(sections, [pat(), pat()]);
+    assert_eq!(reader.pending(), 0);
+}
+
+#[test]
+fn corrupted_section_is_reported() {
+    let mut corrupted = pat();
+    corrupted[9] ^= 1;
+
+    let mut reader = SectionReader::new();
+    reader.push(&corrupted);
+    reader.push(&pat());
+
+    assert!(matches!(
+        reader.next_section(),
+        Some(Err(SectionError::CrcMismatch { table_id: 0 }))
+    ));
+    assert_eq!(reader.next_section().unwrap().unwrap(), pat());
+    assert!(reader.next_section().is_none());
+}
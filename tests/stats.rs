@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::frontend::queries::get::{
+    ErrorBlockCount, Metric, PreErrorBitCount, PreTotalBitCount, SignalStrength, TotalBlockCount,
+    ValueStat, ber, per,
+};
+
+#[test]
+fn decibel_ordering() {
+    let weak = ValueStat::Decibel(-65_500);
+    let strong = ValueStat::Decibel(-42_000);
+    assert!(weak < strong);
+
+    let mut readings = [
+        SignalStrength(vec![Some(strong)]),
+        SignalStrength(vec![None]),
+        SignalStrength(vec![Some(weak)]),
+    ];
+    readings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(readings[0], SignalStrength(vec![None]));
+    assert_eq!(readings[1], SignalStrength(vec![Some(weak)]));
+    assert_eq!(readings[2], SignalStrength(vec![Some(strong)]));
+}
+
+#[test]
+fn mixed_scales_are_not_comparable() {
+    assert_eq!(
+        ValueStat::Decibel(-42_000).partial_cmp(&ValueStat::Relative(30_000)),
+        None
+    );
+}
+
+#[test]
+fn error_rates() {
+    assert_eq!(
+        ber(
+            &PreErrorBitCount(vec![Some(5)]),
+            &PreTotalBitCount(vec![Some(1_000)])
+        ),
+        Some(0.005)
+    );
+    assert_eq!(
+        ber(
+            &PreErrorBitCount(vec![Some(0)]),
+            &PreTotalBitCount(vec![Some(0)])
+        ),
+        None
+    );
+    assert_eq!(
+        per(
+            &ErrorBlockCount(vec![None]),
+            &TotalBlockCount(vec![Some(10)])
+        ),
+        None
+    );
+}
+
+#[test]
+fn percentages() {
+    assert_eq!(
+        ValueStat::Relative(65535).as_percentage(Metric::SignalStrength),
+        100.0
+    );
+    assert_eq!(
+        ValueStat::Relative(0).as_percentage(Metric::SignalStrength),
+        0.0
+    );
+    assert_eq!(
+        ValueStat::Decibel(-55_000).as_percentage(Metric::SignalStrength),
+        50.0
+    );
+    assert_eq!(
+        ValueStat::Decibel(-120_000).as_percentage(Metric::SignalStrength),
+        0.0
+    );
+    assert_eq!(
+        ValueStat::Decibel(40_000).as_percentage(Metric::CarrierSignalToNoise),
+        100.0
+    );
+}
@@ -0,0 +1,22 @@
+use rdvb_os_linux::ts::{TS_PACKET_SIZE, TS_SYNC_BYTE, TsPacket};
+
+#[test]
+fn header_fields() {
+    let mut bytes = [0xFF; TS_PACKET_SIZE];
+    bytes[..4].copy_from_slice(&[TS_SYNC_BYTE, 0x41, 0x00, 0x1A]);
+    let packet = TsPacket::from(bytes);
+
+    assert!(packet.sync_ok());
+    assert!(!packet.transport_error());
+    assert!(packet.payload_unit_start());
+    assert_eq!(packet.pid(), 0x100);
+    assert!(packet.has_payload());
+    assert_eq!(packet.continuity_counter(), 0xA);
+    assert_eq!(
+        format!("{packet:?}"),
+        "TsPacket { sync_ok: true, pid: 0x0100, transport_error: false, payload_unit_start: true, continuity_counter: 10 }"
+    );
+
+    bytes[0] = 0x00;
+    assert!(!TsPacket::from(bytes).sync_ok());
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::{
+    error::TuneRequestError,
+    frontend::{
+        data::{FeDeliverySystem, FeModulation, FeRolloff},
+        lnb::{Lnb, Polarization},
+        property::Command,
+        queries::set::{BandwidthHz, DvbcTuneRequest, DvbsTuneRequest, IsdbtSb},
+    },
+};
+
+#[test]
+fn dvbc_annex_b_defaults_to_qam_64() {
+    let properties = DvbcTuneRequest::new()
+        .annex_b()
+        .frequency(333_000_000)
+        .build()
+        .unwrap();
+
+    let commands: Vec<u32> = properties.iter().map(|p| p.cmd).collect();
+    assert_eq!(
+        commands,
+        [
+            Command::DTV_DELIVERY_SYSTEM as u32,
+            Command::DTV_FREQUENCY as u32,
+            Command::DTV_MODULATION as u32,
+            Command::DTV_INVERSION as u32,
+            Command::DTV_TUNE as u32,
+        ]
+    );
+    assert_eq!(
+        unsafe { properties[0].u.data },
+        FeDeliverySystem::DVBC_ANNEX_B as u32
+    );
+    assert_eq!(unsafe { properties[2].u.data }, FeModulation::QAM_64 as u32);
+}
+
+#[test]
+fn dvbs_missing_or_invalid_parameters() {
+    let request = DvbsTuneRequest::new(Lnb::UNIVERSAL)
+        .frequency(11_494_000)
+        .polarization(Polarization::Horizontal);
+    assert!(matches!(
+        request.clone().build(),
+        Err(TuneRequestError::MissingSymbolRate)
+    ));
+    assert!(matches!(
+        request
+            .symbol_rate(22_000_000)
+            .rolloff(FeRolloff::ROLLOFF_35)
+            .build(),
+        Err(TuneRequestError::InvalidForDeliverySystem {
+            parameter: "rolloff",
+            system: FeDeliverySystem::DVBS,
+        })
+    ));
+}
+
+#[test]
+fn bandwidth_round_trip() {
+    for bandwidth in [
+        BandwidthHz::_1_172MHz,
+        BandwidthHz::_6MHz,
+        BandwidthHz::_8MHz,
+    ] {
+        assert_eq!(BandwidthHz::from_hz(bandwidth.value()), Some(bandwidth));
+    }
+    assert_eq!(BandwidthHz::from_hz(8_000_001), None);
+}
+
+#[test]
+fn isdbt_sb_segment_out_of_range() {
+    assert!(matches!(
+        IsdbtSb::new().segment_idx(3).segment_count(3).build(),
+        Err(TuneRequestError::SegmentOutOfRange { index: 3, count: 3 })
+    ));
+    assert_eq!(
+        IsdbtSb::new()
+            .segment_idx(2)
+            .segment_count(3)
+            .build()
+            .unwrap()
+            .len(),
+        3
+    );
+}
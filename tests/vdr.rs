@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+
+use rdvb_os_linux::{
+    config::vdr::{Channel, parse_channels},
+    error::VdrParseError,
+};
+
+const ASTRA: &str =
+    "Das Erste HD;ARD:11494:HC23M5O35P0S1:S19.2E:22000:5101=27:5102=deu@3:5104:0:10301:1:1019:0";
+
+#[test]
+fn satellite_channel() {
+    let channels = parse_channels(ASTRA).unwrap();
+    assert_eq!(channels.len(), 1);
+}
+
+#[test]
+fn symbol_rate_in_symbols_per_second() {
+    let line = ASTRA.replace(":22000:", ":22000000:");
+    assert!(matches!(
+        line.parse::<Channel>(),
+        Err(VdrParseError::InvalidNumber("symbol rate", _))
+    ));
+}
+
+#[test]
+fn frequency_too_large() {
+    let line = ASTRA.replace(":11494:", ":18446744073709552:");
+    assert!(matches!(
+        line.parse::<Channel>(),
+        Err(VdrParseError::InvalidNumber("frequency", _))
+    ));
+}